@@ -1,6 +1,6 @@
 use core::fmt;
 
-use stylish_core::{Style, Write};
+use stylish_core::{ColorDepth, Style, Write};
 
 use crate::util;
 
@@ -13,10 +13,14 @@ use crate::util;
 /// assert_eq!(writer.finish()?, "Hello \x1b[31mFerris\x1b[0m");
 /// # Ok::<(), core::fmt::Error>(())
 /// ```
+///
+/// Hyperlinks are written as OSC 8 escape sequences, any bytes in the URL that
+/// are not allowed in these sequences are percent-encoded.
 #[derive(Clone, Debug, Default)]
 pub struct Ansi<T: core::fmt::Write> {
     inner: T,
     current: Style,
+    depth: ColorDepth,
 }
 
 impl<T: core::fmt::Write> Ansi<T> {
@@ -25,9 +29,25 @@ impl<T: core::fmt::Write> Ansi<T> {
         Self {
             inner,
             current: Style::default(),
+            depth: ColorDepth::TrueColor,
         }
     }
 
+    /// Downsample all colors written to the nearest color supported at
+    /// `depth`, defaults to [`ColorDepth::TrueColor`].
+    ///
+    /// ```rust
+    /// use stylish::ColorDepth;
+    ///
+    /// let mut writer = stylish::Ansi::new(String::new()).depth(ColorDepth::Ansi16);
+    /// stylish::write!(writer, "Hello {:(fg=#ff0000)}", "Ferris")?;
+    /// assert_eq!(writer.finish()?, "Hello \x1b[91mFerris\x1b[0m");
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    pub fn depth(self, depth: ColorDepth) -> Self {
+        Self { depth, ..self }
+    }
+
     /// Inherent delegation to
     /// [`stylish::Write::write_fmt`](stylish_core::Write::write_fmt) to not
     /// require a trait import.
@@ -52,22 +72,17 @@ impl<T: fmt::Write> Write for Ansi<T> {
             return Ok(());
         }
 
+        let style = style.inherit_from(Style::default()).with(self.depth);
+
         if self.current != style && style == Style::default() {
             self.inner.write_str("\x1b[0m")?;
         } else {
             let diff = style.diff_from(self.current);
-            let segments = [
-                diff.foreground.map(util::foreground),
-                diff.background.map(util::background),
-                diff.intensity.map(util::intensity),
-            ];
-            let mut segments = segments.iter().filter_map(|&s| s);
+            let mut segments = util::segments(diff).into_iter().flatten();
             if let Some(segment) = segments.next() {
-                self.inner.write_str("\x1b[")?;
-                self.inner.write_str(segment)?;
+                write!(self.inner, "\x1b[{segment}")?;
                 for segment in segments {
-                    self.inner.write_str(";")?;
-                    self.inner.write_str(segment)?;
+                    write!(self.inner, ";{segment}")?;
                 }
                 self.inner.write_str("m")?;
             }
@@ -77,4 +92,12 @@ impl<T: fmt::Write> Write for Ansi<T> {
         write!(self.inner, "{s}")?;
         Ok(())
     }
+
+    fn start_link(&mut self, url: &str) -> fmt::Result {
+        write!(self.inner, "\x1b]8;;{}\x1b\\", util::Url(url))
+    }
+
+    fn end_link(&mut self, _url: &str) -> fmt::Result {
+        self.inner.write_str("\x1b]8;;\x1b\\")
+    }
 }
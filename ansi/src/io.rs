@@ -3,7 +3,7 @@
 
 use stylish_core::{
     io::{Result, Write},
-    Style,
+    ColorDepth, Style,
 };
 
 use crate::util;
@@ -21,6 +21,7 @@ use crate::util;
 pub struct Ansi<T: std::io::Write> {
     inner: T,
     current: Style,
+    depth: ColorDepth,
 }
 
 impl<T: std::io::Write> Ansi<T> {
@@ -29,9 +30,25 @@ impl<T: std::io::Write> Ansi<T> {
         Self {
             inner,
             current: Style::default(),
+            depth: ColorDepth::TrueColor,
         }
     }
 
+    /// Downsample all colors written to the nearest color supported at
+    /// `depth`, defaults to [`ColorDepth::TrueColor`].
+    ///
+    /// ```rust
+    /// use stylish::ColorDepth;
+    ///
+    /// let mut writer = stylish::io::Ansi::new(Vec::new()).depth(ColorDepth::Ansi16);
+    /// stylish::write!(writer, "Hello {:(fg=#ff0000)}", "Ferris")?;
+    /// assert_eq!(writer.finish()?, b"Hello \x1b[91mFerris\x1b[0m");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn depth(self, depth: ColorDepth) -> Self {
+        Self { depth, ..self }
+    }
+
     /// Inherent delegation to
     /// [`stylish::io::Write::write_fmt`](stylish_core::io::Write::write_fmt) to
     /// not require a trait import.
@@ -54,22 +71,16 @@ impl<T: std::io::Write> Ansi<T> {
 /// write to the underlying stream until the change is completed.
 impl<T: std::io::Write> Write for Ansi<T> {
     fn write(&mut self, s: &[u8], style: Style) -> Result<usize> {
+        let style = style.inherit_from(Style::default()).with(self.depth);
         if self.current != style && style == Style::default() {
             self.inner.write_all(b"\x1b[0m")?;
         } else {
             let diff = style.diff_from(self.current);
-            let segments = [
-                diff.foreground.map(util::foreground),
-                diff.background.map(util::background),
-                diff.intensity.map(util::intensity),
-            ];
-            let mut segments = segments.iter().filter_map(|&s| s);
+            let mut segments = util::segments(diff).into_iter().flatten();
             if let Some(segment) = segments.next() {
-                self.inner.write_all(b"\x1b[")?;
-                self.inner.write_all(segment.as_bytes())?;
+                write!(self.inner, "\x1b[{segment}")?;
                 for segment in segments {
-                    self.inner.write_all(b";")?;
-                    self.inner.write_all(segment.as_bytes())?;
+                    write!(self.inner, ";{segment}")?;
                 }
                 self.inner.write_all(b"m")?;
             }
@@ -82,4 +93,12 @@ impl<T: std::io::Write> Write for Ansi<T> {
     fn flush(&mut self) -> Result<()> {
         self.inner.flush()
     }
+
+    fn start_link(&mut self, url: &str) -> Result<()> {
+        write!(self.inner, "\x1b]8;;{}\x1b\\", util::Url(url))
+    }
+
+    fn end_link(&mut self, _url: &str) -> Result<()> {
+        self.inner.write_all(b"\x1b]8;;\x1b\\")
+    }
 }
@@ -1,7 +1,46 @@
-use stylish_core::{Color, Intensity};
+use core::fmt;
 
-pub(crate) fn foreground(color: Color) -> &'static str {
-    match color {
+use stylish_core::{Color, Intensity, StyleDiff, Underline};
+
+/// A single parameter of an SGR escape sequence, some of which must be
+/// computed at runtime.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Segment {
+    Static(&'static str),
+    Indexed(&'static str, u8),
+    Rgb(&'static str, (u8, u8, u8)),
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Segment::Static(segment) => f.write_str(segment),
+            Segment::Indexed(prefix, index) => write!(f, "{prefix};5;{index}"),
+            Segment::Rgb(prefix, (r, g, b)) => write!(f, "{prefix};2;{r};{g};{b}"),
+        }
+    }
+}
+
+/// A URL with any bytes that may not appear within an OSC 8 escape sequence
+/// percent-encoded.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Url<'a>(pub(crate) &'a str);
+
+impl fmt::Display for Url<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0.bytes() {
+            if (0x21..=0x7e).contains(&byte) {
+                fmt::Write::write_char(f, char::from(byte))?;
+            } else {
+                write!(f, "%{byte:02X}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn foreground(color: Color) -> Segment {
+    Segment::Static(match color {
         Color::Black => "30",
         Color::Red => "31",
         Color::Green => "32",
@@ -21,12 +60,15 @@ pub(crate) fn foreground(color: Color) -> &'static str {
         Color::BrightCyan => "96",
         Color::BrightWhite => "97",
 
+        Color::Ansi256(index) => return Segment::Indexed("38", index),
+        Color::Rgb(r, g, b) => return Segment::Rgb("38", (r, g, b)),
+
         _ => "39",
-    }
+    })
 }
 
-pub(crate) fn background(color: Color) -> &'static str {
-    match color {
+pub(crate) fn background(color: Color) -> Segment {
+    Segment::Static(match color {
         Color::Black => "40",
         Color::Red => "41",
         Color::Green => "42",
@@ -46,15 +88,80 @@ pub(crate) fn background(color: Color) -> &'static str {
         Color::BrightCyan => "106",
         Color::BrightWhite => "107",
 
+        Color::Ansi256(index) => return Segment::Indexed("48", index),
+        Color::Rgb(r, g, b) => return Segment::Rgb("48", (r, g, b)),
+
         _ => "49",
+    })
+}
+
+pub(crate) fn underline_color(color: Color) -> Segment {
+    match color {
+        Color::Black => Segment::Indexed("58", 0),
+        Color::Red => Segment::Indexed("58", 1),
+        Color::Green => Segment::Indexed("58", 2),
+        Color::Yellow => Segment::Indexed("58", 3),
+        Color::Blue => Segment::Indexed("58", 4),
+        Color::Magenta => Segment::Indexed("58", 5),
+        Color::Cyan => Segment::Indexed("58", 6),
+        Color::White => Segment::Indexed("58", 7),
+        Color::Default => Segment::Static("59"),
+
+        Color::BrightBlack => Segment::Indexed("58", 8),
+        Color::BrightRed => Segment::Indexed("58", 9),
+        Color::BrightGreen => Segment::Indexed("58", 10),
+        Color::BrightYellow => Segment::Indexed("58", 11),
+        Color::BrightBlue => Segment::Indexed("58", 12),
+        Color::BrightMagenta => Segment::Indexed("58", 13),
+        Color::BrightCyan => Segment::Indexed("58", 14),
+        Color::BrightWhite => Segment::Indexed("58", 15),
+
+        Color::Ansi256(index) => Segment::Indexed("58", index),
+        Color::Rgb(r, g, b) => Segment::Rgb("58", (r, g, b)),
+
+        _ => Segment::Static("59"),
     }
 }
 
-pub(crate) fn intensity(intensity: Intensity) -> &'static str {
-    match intensity {
+pub(crate) fn intensity(intensity: Intensity) -> Segment {
+    Segment::Static(match intensity {
         Intensity::Bold => "1",
         Intensity::Faint => "2",
         Intensity::Normal => "22",
         _ => "22",
-    }
+    })
+}
+
+pub(crate) fn underline(underline: Underline) -> Segment {
+    Segment::Static(match underline {
+        Underline::Single => "4",
+        Underline::Double => "4:2",
+        Underline::Curly => "4:3",
+        Underline::Dotted => "4:4",
+        Underline::Dashed => "4:5",
+        Underline::None => "24",
+        _ => "4",
+    })
+}
+
+fn flag(set: &'static str, unset: &'static str) -> impl Fn(bool) -> Segment {
+    move |value| Segment::Static(if value { set } else { unset })
+}
+
+/// All the SGR parameters required to apply `diff`, in the order they should be
+/// written.
+pub(crate) fn segments(diff: StyleDiff) -> [Option<Segment>; 11] {
+    [
+        diff.foreground.map(foreground),
+        diff.background.map(background),
+        diff.intensity.map(intensity),
+        diff.italic.map(flag("3", "23")),
+        diff.underline.map(underline),
+        diff.underline_color.map(underline_color),
+        diff.blink.map(flag("5", "25")),
+        diff.reverse.map(flag("7", "27")),
+        diff.hidden.map(flag("8", "28")),
+        diff.strikethrough.map(flag("9", "29")),
+        diff.overline.map(flag("53", "55")),
+    ]
 }
@@ -1,4 +1,9 @@
-use crate::{Display, Formatter, Result};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    formatter::{Align, FormatterArgs},
+    Debug, Display, Formatter, Result, StyleDiff,
+};
 
 #[doc(hidden)]
 /// pub for macros
@@ -40,6 +45,43 @@ pub struct StdFmtDebug<'a>(
     pub StdFmt<'a>,
 );
 
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+/// pub for macros
+pub struct Padded<'a, T: ?Sized> {
+    #[doc(hidden)]
+    /// pub for macros
+    pub value: &'a T,
+    #[doc(hidden)]
+    /// pub for macros
+    pub classes: &'a [&'a str],
+    #[doc(hidden)]
+    /// pub for macros
+    pub style: StyleDiff,
+}
+
+/// Whether the formatting arguments need padding applied outside of std, as
+/// std can only be given a fill character known at compile time.
+fn custom_fill(f: &Formatter<'_>) -> bool {
+    f.format.fill != ' ' && f.format.width.is_some() && !f.format.zero
+}
+
+/// The formatting arguments for std to use when the padding is applied
+/// separately.
+fn without_padding(format: FormatterArgs<'_>) -> FormatterArgs<'_> {
+    FormatterArgs {
+        fill: ' ',
+        align: None,
+        width: None,
+        ..format
+    }
+}
+
+/// The number of `char`s in `s`, which is how std measures padding.
+fn char_count(s: &str) -> usize {
+    s.chars().count()
+}
+
 /// A precompiled version of a format string and its by-reference arguments.
 ///
 /// Currently this can only be constructed via [`stylish::format_args!`], but it
@@ -63,6 +105,12 @@ impl Display for StdFmtOther<'_> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         let arg = &self.0;
+        if custom_fill(f) {
+            let format = without_padding(f.format);
+            return f.padded(char_count, None, format, Align::Left, &|f| {
+                std_write!(f, Other, arg)
+            });
+        }
         std_write!(f, Other, arg)
     }
 }
@@ -71,13 +119,47 @@ impl Display for StdFmtDebug<'_> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         let arg = &self.0;
+        if custom_fill(f) {
+            let format = without_padding(f.format);
+            return f.padded(char_count, None, format, Align::Left, &|f| {
+                std_write!(f, Debug, arg)
+            });
+        }
         std_write!(f, Debug, arg)
     }
 }
 
+impl<T: Display + ?Sized> Display for Padded<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let content = |f: &mut Formatter<'_>| {
+            self.value
+                .fmt(&mut f.with_classes(self.classes, self.style))
+        };
+        if f.format.width.is_none() && f.format.precision.is_none() {
+            return content(f);
+        }
+        let (precision, format) = (f.format.precision.copied(), f.format);
+        f.padded(
+            UnicodeWidthStr::width,
+            precision,
+            format,
+            Align::Left,
+            &content,
+        )
+    }
+}
+
 impl Display for Arguments<'_> {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         (self.f)(f)
     }
 }
+
+impl Debug for Arguments<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (self.f)(f)
+    }
+}
@@ -0,0 +1,783 @@
+use crate::{debug::Highlight, Debug, Formatter, Restyle, Result, Style, StyleDiff, Write};
+
+/// Indents everything written through it by one level, used for the `{:#s?}`
+/// pretty-printed output of the builders. The indentation is written in
+/// `style` rather than the style of the line it starts.
+struct PadAdapter<'a, 'b> {
+    write: &'a mut (dyn Write + 'b),
+    style: Style,
+    on_newline: &'a mut bool,
+}
+
+impl Write for PadAdapter<'_, '_> {
+    fn write_str(&mut self, s: &str, style: Style) -> Result {
+        for line in s.split_inclusive('\n') {
+            if *self.on_newline {
+                self.write.write_str("    ", self.style)?;
+            }
+            *self.on_newline = line.ends_with('\n');
+            self.write.write_str(line, style)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result {
+        self.write.start_link(url)
+    }
+
+    #[inline]
+    fn end_link(&mut self, url: &str) -> Result {
+        self.write.end_link(url)
+    }
+}
+
+/// Calls `content` with a sub-`Formatter` that indents its output by one
+/// level, `on_newline` tracks whether the next write starts a new line.
+fn indented(
+    fmt: &mut Formatter<'_>,
+    on_newline: &mut bool,
+    content: impl FnOnce(&mut Formatter<'_>) -> Result,
+) -> Result {
+    let Formatter {
+        style,
+        format,
+        linked,
+        theme,
+        handled_args,
+        ..
+    } = *fmt;
+    content(&mut Formatter {
+        style,
+        format,
+        linked,
+        theme,
+        write: &mut PadAdapter {
+            write: &mut *fmt.write,
+            style,
+            on_newline,
+        },
+        handled_args,
+    })
+}
+
+/// The styles each part of the builders' output is written in, punctuation
+/// defaults to the `debug.punctuation` class of the current theme.
+#[derive(Clone, Copy, Debug)]
+struct Styles {
+    name: Style,
+    punctuation: Style,
+    value: Style,
+}
+
+impl Styles {
+    fn new(fmt: &Formatter<'_>) -> Self {
+        Self {
+            name: fmt.style,
+            punctuation: fmt.style.with(Highlight::Punctuation.style(fmt)),
+            value: fmt.style,
+        }
+    }
+}
+
+/// A struct to help with [`stylish::Debug`] implementations, in the style
+/// of [`core::fmt::DebugStruct`].
+///
+/// Constructed by [`Formatter::debug_struct`], the name of the struct is
+/// written in the current style while the field names, punctuation and values
+/// can be styled independently.
+///
+/// ```rust
+/// use stylish::{Intensity, Underline};
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl stylish::Debug for Point {
+///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+///         f.debug_struct("Point")
+///             .name_style(Intensity::Bold)
+///             .value_style(Underline::Single)
+///             .field("x", &self.x)
+///             .field("y", &self.y)
+///             .finish()
+///     }
+/// }
+///
+/// assert_eq!(
+///     stylish::plain::format!("{:s?}", Point { x: 1, y: 2 }),
+///     "Point { x: 1, y: 2 }",
+/// );
+/// assert_eq!(
+///     stylish::html::format!("{:s?}", Point { x: 1, y: 2 }),
+///     "Point { <span style=font-weight:bolder>x</span>: \
+///     <span style=color:cyan;text-decoration-line:underline>1</span>, \
+///     <span style=font-weight:bolder>y</span>: \
+///     <span style=color:cyan;text-decoration-line:underline>2</span> }",
+/// );
+/// ```
+#[must_use = "must eventually call `finish()` on Debug builders"]
+#[allow(missing_debug_implementations)]
+pub struct DebugStruct<'a, 'b: 'a> {
+    fmt: &'a mut Formatter<'b>,
+    styles: Styles,
+    result: Result,
+    has_fields: bool,
+}
+
+impl<'a, 'b: 'a> DebugStruct<'a, 'b> {
+    pub(crate) fn new(fmt: &'a mut Formatter<'b>, name: &str) -> Self {
+        let result = fmt.write_str(name);
+        Self {
+            styles: Styles::new(fmt),
+            fmt,
+            result,
+            has_fields: false,
+        }
+    }
+
+    /// Applies `restyle` to the field names written after this.
+    pub fn name_style(&mut self, restyle: impl Restyle) -> &mut Self {
+        self.styles.name = self.fmt.style.with(restyle);
+        self
+    }
+
+    /// Applies `restyle` to the braces, separators and `..` written after
+    /// this.
+    pub fn punctuation_style(&mut self, restyle: impl Restyle) -> &mut Self {
+        self.styles.punctuation = self.fmt.style.with(restyle);
+        self
+    }
+
+    /// Applies `restyle` to the field values written after this.
+    pub fn value_style(&mut self, restyle: impl Restyle) -> &mut Self {
+        self.styles.value = self.fmt.style.with(restyle);
+        self
+    }
+
+    /// Adds a new field to the generated struct output.
+    pub fn field(&mut self, name: &str, value: &dyn Debug) -> &mut Self {
+        let styles = self.styles;
+        self.result = self.result.and_then(|_| {
+            if self.fmt.alternate() {
+                if !self.has_fields {
+                    self.fmt.with(styles.punctuation).write_str(" {\n")?;
+                }
+                indented(self.fmt, &mut true, |f| {
+                    f.with(styles.name).write_str(name)?;
+                    f.with(styles.punctuation).write_str(": ")?;
+                    value.fmt(&mut f.with(styles.value))?;
+                    f.with(styles.punctuation).write_str(",\n")
+                })
+            } else {
+                let prefix = if self.has_fields { ", " } else { " { " };
+                self.fmt.with(styles.punctuation).write_str(prefix)?;
+                self.fmt.with(styles.name).write_str(name)?;
+                self.fmt.with(styles.punctuation).write_str(": ")?;
+                value.fmt(&mut self.fmt.with(styles.value))
+            }
+        });
+        self.has_fields = true;
+        self
+    }
+
+    /// Marks the struct as non-exhaustive, indicating to the reader that
+    /// there are some other fields that are not shown, and finishes output.
+    pub fn finish_non_exhaustive(&mut self) -> Result {
+        let punctuation = self.styles.punctuation;
+        self.result = self.result.and_then(|_| {
+            if self.fmt.alternate() {
+                if !self.has_fields {
+                    self.fmt.with(punctuation).write_str(" {\n")?;
+                }
+                indented(self.fmt, &mut true, |f| {
+                    f.with(punctuation).write_str("..\n")
+                })?;
+                self.fmt.with(punctuation).write_str("}")
+            } else {
+                let suffix = if self.has_fields { ", .. }" } else { " { .. }" };
+                self.fmt.with(punctuation).write_str(suffix)
+            }
+        });
+        self.result
+    }
+
+    /// Finishes output and returns any error encountered.
+    pub fn finish(&mut self) -> Result {
+        let punctuation = self.styles.punctuation;
+        if self.has_fields {
+            self.result = self.result.and_then(|_| {
+                let suffix = if self.fmt.alternate() { "}" } else { " }" };
+                self.fmt.with(punctuation).write_str(suffix)
+            });
+        }
+        self.result
+    }
+}
+
+/// A struct to help with [`stylish::Debug`] implementations, in the style
+/// of [`core::fmt::DebugTuple`].
+///
+/// Constructed by [`Formatter::debug_tuple`], the name of the tuple is
+/// written in the current style while the punctuation and values can be styled
+/// independently.
+///
+/// ```rust
+/// use stylish::Intensity;
+///
+/// struct Meters(f64);
+///
+/// impl stylish::Debug for Meters {
+///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+///         f.debug_tuple("Meters")
+///             .value_style(Intensity::Bold)
+///             .field(&self.0)
+///             .finish()
+///     }
+/// }
+///
+/// assert_eq!(
+///     stylish::html::format!("{:s?}", Meters(1.5)),
+///     "Meters(<span style=color:cyan;font-weight:bolder>1.5</span>)",
+/// );
+/// ```
+#[must_use = "must eventually call `finish()` on Debug builders"]
+#[allow(missing_debug_implementations)]
+pub struct DebugTuple<'a, 'b: 'a> {
+    fmt: &'a mut Formatter<'b>,
+    styles: Styles,
+    result: Result,
+    fields: usize,
+    empty_name: bool,
+}
+
+impl<'a, 'b: 'a> DebugTuple<'a, 'b> {
+    pub(crate) fn new(fmt: &'a mut Formatter<'b>, name: &str, name_style: StyleDiff) -> Self {
+        let result = fmt.with(name_style).write_str(name);
+        Self {
+            styles: Styles::new(fmt),
+            fmt,
+            result,
+            fields: 0,
+            empty_name: name.is_empty(),
+        }
+    }
+
+    /// Applies `restyle` to the parentheses, separators and `..` written
+    /// after this.
+    pub fn punctuation_style(&mut self, restyle: impl Restyle) -> &mut Self {
+        self.styles.punctuation = self.fmt.style.with(restyle);
+        self
+    }
+
+    /// Applies `restyle` to the field values written after this.
+    pub fn value_style(&mut self, restyle: impl Restyle) -> &mut Self {
+        self.styles.value = self.fmt.style.with(restyle);
+        self
+    }
+
+    /// Adds a new field to the generated tuple struct output.
+    pub fn field(&mut self, value: &dyn Debug) -> &mut Self {
+        let styles = self.styles;
+        self.result = self.result.and_then(|_| {
+            if self.fmt.alternate() {
+                if self.fields == 0 {
+                    self.fmt.with(styles.punctuation).write_str("(\n")?;
+                }
+                indented(self.fmt, &mut true, |f| {
+                    value.fmt(&mut f.with(styles.value))?;
+                    f.with(styles.punctuation).write_str(",\n")
+                })
+            } else {
+                let prefix = if self.fields == 0 { "(" } else { ", " };
+                self.fmt.with(styles.punctuation).write_str(prefix)?;
+                value.fmt(&mut self.fmt.with(styles.value))
+            }
+        });
+        self.fields += 1;
+        self
+    }
+
+    /// Marks the tuple struct as non-exhaustive, indicating to the reader
+    /// that there are some other fields that are not shown, and finishes
+    /// output.
+    pub fn finish_non_exhaustive(&mut self) -> Result {
+        let punctuation = self.styles.punctuation;
+        self.result = self.result.and_then(|_| {
+            if self.fmt.alternate() {
+                if self.fields == 0 {
+                    self.fmt.with(punctuation).write_str("(\n")?;
+                }
+                indented(self.fmt, &mut true, |f| {
+                    f.with(punctuation).write_str("..\n")
+                })?;
+                self.fmt.with(punctuation).write_str(")")
+            } else {
+                let suffix = if self.fields == 0 { "(..)" } else { ", ..)" };
+                self.fmt.with(punctuation).write_str(suffix)
+            }
+        });
+        self.result
+    }
+
+    /// Finishes output and returns any error encountered.
+    pub fn finish(&mut self) -> Result {
+        let punctuation = self.styles.punctuation;
+        if self.fields > 0 {
+            self.result = self.result.and_then(|_| {
+                if self.fields == 1 && self.empty_name && !self.fmt.alternate() {
+                    self.fmt.with(punctuation).write_str(",")?;
+                }
+                self.fmt.with(punctuation).write_str(")")
+            });
+        }
+        self.result
+    }
+}
+
+/// The shared implementation of [`DebugList`] and [`DebugSet`], which only
+/// differ in their delimiters.
+///
+/// Unlike the names of structs and tuples the opening delimiter is written
+/// lazily, so that it is styled by `punctuation_style` too.
+struct DebugInner<'a, 'b: 'a> {
+    fmt: &'a mut Formatter<'b>,
+    styles: Styles,
+    result: Result,
+    delimiters: (&'static str, &'static str),
+    opened: bool,
+    has_fields: bool,
+}
+
+impl<'a, 'b: 'a> DebugInner<'a, 'b> {
+    fn new(fmt: &'a mut Formatter<'b>, delimiters: (&'static str, &'static str)) -> Self {
+        Self {
+            styles: Styles::new(fmt),
+            fmt,
+            result: Ok(()),
+            delimiters,
+            opened: false,
+            has_fields: false,
+        }
+    }
+
+    fn open(&mut self) -> Result {
+        if !self.opened {
+            self.opened = true;
+            self.fmt
+                .with(self.styles.punctuation)
+                .write_str(self.delimiters.0)?;
+        }
+        Ok(())
+    }
+
+    fn entry(&mut self, entry: &dyn Debug) {
+        let styles = self.styles;
+        self.result = self.result.and_then(|_| {
+            self.open()?;
+            if self.fmt.alternate() {
+                if !self.has_fields {
+                    self.fmt.with(styles.punctuation).write_str("\n")?;
+                }
+                indented(self.fmt, &mut true, |f| {
+                    entry.fmt(&mut f.with(styles.value))?;
+                    f.with(styles.punctuation).write_str(",\n")
+                })
+            } else {
+                if self.has_fields {
+                    self.fmt.with(styles.punctuation).write_str(", ")?;
+                }
+                entry.fmt(&mut self.fmt.with(styles.value))
+            }
+        });
+        self.has_fields = true;
+    }
+
+    fn finish_non_exhaustive(&mut self) -> Result {
+        let punctuation = self.styles.punctuation;
+        self.result = self.result.and_then(|_| {
+            self.open()?;
+            if self.fmt.alternate() {
+                if !self.has_fields {
+                    self.fmt.with(punctuation).write_str("\n")?;
+                }
+                indented(self.fmt, &mut true, |f| {
+                    f.with(punctuation).write_str("..\n")
+                })?;
+            } else if self.has_fields {
+                self.fmt.with(punctuation).write_str(", ..")?;
+            } else {
+                self.fmt.with(punctuation).write_str("..")?;
+            }
+            self.fmt.with(punctuation).write_str(self.delimiters.1)
+        });
+        self.result
+    }
+
+    fn finish(&mut self) -> Result {
+        let punctuation = self.styles.punctuation;
+        self.result = self.result.and_then(|_| {
+            self.open()?;
+            self.fmt.with(punctuation).write_str(self.delimiters.1)
+        });
+        self.result
+    }
+}
+
+macro_rules! debug_inner {
+    ($(#[$attr:meta])* $name:ident, $delimiters:expr, $punctuation:literal, $parts:literal) => {
+        $(#[$attr])*
+        #[must_use = "must eventually call `finish()` on Debug builders"]
+        #[allow(missing_debug_implementations)]
+        pub struct $name<'a, 'b: 'a> {
+            inner: DebugInner<'a, 'b>,
+        }
+
+        impl<'a, 'b: 'a> $name<'a, 'b> {
+            pub(crate) fn new(fmt: &'a mut Formatter<'b>) -> Self {
+                Self {
+                    inner: DebugInner::new(fmt, $delimiters),
+                }
+            }
+
+            #[doc = concat!("Applies `restyle` to the ", $punctuation, ", separators and `..`.")]
+            pub fn punctuation_style(&mut self, restyle: impl Restyle) -> &mut Self {
+                self.inner.styles.punctuation = self.inner.fmt.style.with(restyle);
+                self
+            }
+
+            /// Applies `restyle` to the entries written after this.
+            pub fn value_style(&mut self, restyle: impl Restyle) -> &mut Self {
+                self.inner.styles.value = self.inner.fmt.style.with(restyle);
+                self
+            }
+
+            #[doc = concat!("Adds a new entry to the ", $parts, " output.")]
+            pub fn entry(&mut self, entry: &dyn Debug) -> &mut Self {
+                self.inner.entry(entry);
+                self
+            }
+
+            #[doc = concat!("Adds the contents of an iterator of entries to the ", $parts, " output.")]
+            pub fn entries<D, I>(&mut self, entries: I) -> &mut Self
+            where
+                D: Debug,
+                I: IntoIterator<Item = D>,
+            {
+                for entry in entries {
+                    self.inner.entry(&entry);
+                }
+                self
+            }
+
+            #[doc = concat!("Marks the ", $parts, " as non-exhaustive, indicating to the reader that")]
+            /// there are some other elements that are not shown, and finishes
+            /// output.
+            pub fn finish_non_exhaustive(&mut self) -> Result {
+                self.inner.finish_non_exhaustive()
+            }
+
+            /// Finishes output and returns any error encountered.
+            pub fn finish(&mut self) -> Result {
+                self.inner.finish()
+            }
+        }
+    };
+}
+
+debug_inner! {
+    /// A struct to help with [`stylish::Debug`] implementations, in the
+    /// style of [`core::fmt::DebugList`].
+    ///
+    /// Constructed by [`Formatter::debug_list`], the punctuation and entries
+    /// can be styled independently.
+    ///
+    /// ```rust
+    /// use stylish::{Color, Foreground};
+    ///
+    /// struct Path(Vec<&'static str>);
+    ///
+    /// impl stylish::Debug for Path {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         let mut list = f.debug_list();
+    ///         list.punctuation_style(Foreground(Color::Red));
+    ///         for segment in &self.0 {
+    ///             list.entry(segment);
+    ///         }
+    ///         list.finish()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     stylish::html::format!("{:s?}", Path(vec!["a", "b"])),
+    ///     "<span style=color:red>[</span><span style=color:green>&quot;a&quot;</span>\
+    ///     <span style=color:red>, </span><span style=color:green>&quot;b&quot;</span>\
+    ///     <span style=color:red>]</span>",
+    /// );
+    /// ```
+    DebugList, ("[", "]"), "brackets", "list"
+}
+
+debug_inner! {
+    /// A struct to help with [`stylish::Debug`] implementations, in the
+    /// style of [`core::fmt::DebugSet`].
+    ///
+    /// Constructed by [`Formatter::debug_set`], the punctuation and entries
+    /// can be styled independently.
+    ///
+    /// ```rust
+    /// use stylish::Intensity;
+    ///
+    /// struct Flags(u8);
+    ///
+    /// impl stylish::Debug for Flags {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         let mut set = f.debug_set();
+    ///         set.value_style(Intensity::Bold);
+    ///         for (i, name) in ["read", "write", "exec"].iter().enumerate() {
+    ///             if self.0 & (1 << i) != 0 {
+    ///                 set.entry(name);
+    ///             }
+    ///         }
+    ///         set.finish()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     stylish::html::format!("{:s?}", Flags(0b101)),
+    ///     "{<span style=color:green;font-weight:bolder>&quot;read&quot;</span>, \
+    ///     <span style=color:green;font-weight:bolder>&quot;exec&quot;</span>}",
+    /// );
+    /// ```
+    DebugSet, ("{", "}"), "braces", "set"
+}
+
+/// A struct to help with [`stylish::Debug`] implementations, in the style
+/// of [`core::fmt::DebugMap`].
+///
+/// Constructed by [`Formatter::debug_map`], the keys, punctuation and values
+/// can be styled independently.
+///
+/// ```rust
+/// use stylish::{Intensity, Underline};
+///
+/// struct Env(Vec<(&'static str, &'static str)>);
+///
+/// impl stylish::Debug for Env {
+///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+///         let mut map = f.debug_map();
+///         map.key_style(Intensity::Bold)
+///             .value_style(Underline::Single);
+///         for (key, value) in &self.0 {
+///             map.entry(key, value);
+///         }
+///         map.finish()
+///     }
+/// }
+///
+/// assert_eq!(
+///     stylish::plain::format!("{:#s?}", Env(vec![("HOME", "/root")])),
+///     "{\n    \"HOME\": \"/root\",\n}",
+/// );
+/// assert_eq!(
+///     stylish::html::format!("{:s?}", Env(vec![("HOME", "/root")])),
+///     "{<span style=color:green;font-weight:bolder>&quot;HOME&quot;</span>: \
+///     <span style=color:green;text-decoration-line:underline>&quot;/root&quot;</span>}",
+/// );
+/// ```
+#[must_use = "must eventually call `finish()` on Debug builders"]
+#[allow(missing_debug_implementations)]
+pub struct DebugMap<'a, 'b: 'a> {
+    fmt: &'a mut Formatter<'b>,
+    styles: Styles,
+    result: Result,
+    opened: bool,
+    has_fields: bool,
+    has_key: bool,
+    /// Whether the pretty-printed output is at the start of a line, kept
+    /// between writing a key and its value.
+    on_newline: bool,
+}
+
+impl<'a, 'b: 'a> DebugMap<'a, 'b> {
+    pub(crate) fn new(fmt: &'a mut Formatter<'b>) -> Self {
+        Self {
+            styles: Styles::new(fmt),
+            fmt,
+            result: Ok(()),
+            opened: false,
+            has_fields: false,
+            has_key: false,
+            on_newline: true,
+        }
+    }
+
+    /// Applies `restyle` to the keys written after this.
+    pub fn key_style(&mut self, restyle: impl Restyle) -> &mut Self {
+        self.styles.name = self.fmt.style.with(restyle);
+        self
+    }
+
+    /// Applies `restyle` to the braces, separators and `..`.
+    pub fn punctuation_style(&mut self, restyle: impl Restyle) -> &mut Self {
+        self.styles.punctuation = self.fmt.style.with(restyle);
+        self
+    }
+
+    /// Applies `restyle` to the values written after this.
+    pub fn value_style(&mut self, restyle: impl Restyle) -> &mut Self {
+        self.styles.value = self.fmt.style.with(restyle);
+        self
+    }
+
+    /// Adds a new entry to the map output.
+    pub fn entry(&mut self, key: &dyn Debug, value: &dyn Debug) -> &mut Self {
+        self.key(key).value(value)
+    }
+
+    /// Adds the key part of a new entry to the map output.
+    ///
+    /// This method, together with [`value`](DebugMap::value), is an
+    /// alternative to [`entry`](DebugMap::entry) that can be used when the
+    /// complete entry isn't known upfront.
+    ///
+    /// # Panics
+    ///
+    /// `key` must be called before `value` and each call to `key` must be
+    /// followed by a corresponding call to `value`. Otherwise this method will
+    /// panic.
+    pub fn key(&mut self, key: &dyn Debug) -> &mut Self {
+        let styles = self.styles;
+        self.result = self.result.and_then(|_| {
+            assert!(
+                !self.has_key,
+                "attempted to begin a new map entry without completing the previous one"
+            );
+            self.open()?;
+            if self.fmt.alternate() {
+                if !self.has_fields {
+                    self.fmt.with(styles.punctuation).write_str("\n")?;
+                }
+                self.on_newline = true;
+                indented(self.fmt, &mut self.on_newline, |f| {
+                    key.fmt(&mut f.with(styles.name))?;
+                    f.with(styles.punctuation).write_str(": ")
+                })?;
+            } else {
+                if self.has_fields {
+                    self.fmt.with(styles.punctuation).write_str(", ")?;
+                }
+                key.fmt(&mut self.fmt.with(styles.name))?;
+                self.fmt.with(styles.punctuation).write_str(": ")?;
+            }
+            self.has_key = true;
+            Ok(())
+        });
+        self
+    }
+
+    /// Adds the value part of a new entry to the map output.
+    ///
+    /// # Panics
+    ///
+    /// `key` must be called before `value` and each call to `key` must be
+    /// followed by a corresponding call to `value`. Otherwise this method will
+    /// panic.
+    pub fn value(&mut self, value: &dyn Debug) -> &mut Self {
+        let styles = self.styles;
+        self.result = self.result.and_then(|_| {
+            assert!(
+                self.has_key,
+                "attempted to format a map value before its key"
+            );
+            if self.fmt.alternate() {
+                indented(self.fmt, &mut self.on_newline, |f| {
+                    value.fmt(&mut f.with(styles.value))?;
+                    f.with(styles.punctuation).write_str(",\n")
+                })?;
+            } else {
+                value.fmt(&mut self.fmt.with(styles.value))?;
+            }
+            self.has_key = false;
+            Ok(())
+        });
+        self.has_fields = true;
+        self
+    }
+
+    /// Adds the contents of an iterator of entries to the map output.
+    pub fn entries<K, V, I>(&mut self, entries: I) -> &mut Self
+    where
+        K: Debug,
+        V: Debug,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        for (key, value) in entries {
+            self.entry(&key, &value);
+        }
+        self
+    }
+
+    /// Marks the map as non-exhaustive, indicating to the reader that there
+    /// are some other entries that are not shown, and finishes output.
+    ///
+    /// # Panics
+    ///
+    /// `key` must be called before `value` and each call to `key` must be
+    /// followed by a corresponding call to `value`. Otherwise this method will
+    /// panic.
+    pub fn finish_non_exhaustive(&mut self) -> Result {
+        let punctuation = self.styles.punctuation;
+        self.result = self.result.and_then(|_| {
+            assert!(
+                !self.has_key,
+                "attempted to finish a map with a partial entry"
+            );
+            self.open()?;
+            if self.fmt.alternate() {
+                if !self.has_fields {
+                    self.fmt.with(punctuation).write_str("\n")?;
+                }
+                indented(self.fmt, &mut true, |f| {
+                    f.with(punctuation).write_str("..\n")
+                })?;
+            } else if self.has_fields {
+                self.fmt.with(punctuation).write_str(", ..")?;
+            } else {
+                self.fmt.with(punctuation).write_str("..")?;
+            }
+            self.fmt.with(punctuation).write_str("}")
+        });
+        self.result
+    }
+
+    /// Finishes output and returns any error encountered.
+    ///
+    /// # Panics
+    ///
+    /// `key` must be called before `value` and each call to `key` must be
+    /// followed by a corresponding call to `value`. Otherwise this method will
+    /// panic.
+    pub fn finish(&mut self) -> Result {
+        let punctuation = self.styles.punctuation;
+        self.result = self.result.and_then(|_| {
+            assert!(
+                !self.has_key,
+                "attempted to finish a map with a partial entry"
+            );
+            self.open()?;
+            self.fmt.with(punctuation).write_str("}")
+        });
+        self.result
+    }
+
+    fn open(&mut self) -> Result {
+        if !self.opened {
+            self.opened = true;
+            self.fmt.with(self.styles.punctuation).write_str("{")?;
+        }
+        Ok(())
+    }
+}
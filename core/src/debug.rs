@@ -0,0 +1,367 @@
+use crate::{
+    arguments::{StdFmt, StdFmtDebug},
+    Color, Display, Formatter, Result, StyleDiff,
+};
+
+/// Format trait for the stylish debug format, `{:s?}`.
+///
+/// `Debug` is similar to [`core::fmt::Debug`], but allows attaching additional
+/// style attributes to the output. It is implemented for the primitive types,
+/// strings, [`Option`], [`Result`](core::result::Result), tuples, slices,
+/// arrays and the standard collections, highlighting their parts using these
+/// [`Theme`](crate::Theme) classes:
+///
+/// | Class               | Parts                                      | Default     |
+/// |---------------------|--------------------------------------------|-------------|
+/// | `debug.number`      | integers and floats                        | `fg=cyan`   |
+/// | `debug.string`      | strings and characters                     | `fg=green`  |
+/// | `debug.keyword`     | `true`, `false`, `None`, `Some`, `Ok` and `Err` | `fg=magenta` |
+/// | `debug.punctuation` | brackets and separators from the builders  |             |
+///
+/// Types that only implement [`core::fmt::Debug`] can still be formatted with
+/// `{:s?}` in the [`stylish`] macros, falling back to their unstyled `Debug`
+/// output.
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// let map = BTreeMap::from([("a", Some(1)), ("b", None)]);
+///
+/// assert_eq!(
+///     stylish::plain::format!("{:s?}", map),
+///     r#"{"a": Some(1), "b": None}"#,
+/// );
+/// assert_eq!(
+///     stylish::html::format!("{:s?}", map),
+///     "{<span style=color:green>&quot;a&quot;</span>: \
+///     <span style=color:magenta>Some</span>(<span style=color:cyan>1</span>), \
+///     <span style=color:green>&quot;b&quot;</span>: <span style=color:magenta>None</span>}",
+/// );
+///
+/// let theme = [("debug.number", "fg=red".parse()?), ("debug.punctuation", "faint".parse()?)];
+/// let mut writer = stylish::Themed::new(stylish::html(String::new()), theme);
+/// stylish::write!(writer, "{:s?}", [1])?;
+/// assert_eq!(
+///     writer.into_inner().finish()?,
+///     "<span style=font-weight:lighter>[</span>\
+///     <span style=color:red>1</span>\
+///     <span style=font-weight:lighter>]</span>",
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// Implementations can use the [`Formatter::debug_struct`] family of builders
+/// to get the same layout and highlighting as the standard library types,
+/// including the `{:#s?}` pretty-printed format.
+///
+/// ```rust
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl stylish::Debug for Point {
+///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+///         f.debug_struct("Point")
+///             .field("x", &self.x)
+///             .field("y", &self.y)
+///             .finish()
+///     }
+/// }
+///
+/// assert_eq!(
+///     stylish::plain::format!("{:s?}", Point { x: 1, y: 2 }),
+///     "Point { x: 1, y: 2 }",
+/// );
+/// assert_eq!(
+///     stylish::html::format!("{:s?}", Point { x: 1, y: 2 }),
+///     "Point { x: <span style=color:cyan>1</span>, y: <span style=color:cyan>2</span> }",
+/// );
+/// ```
+pub trait Debug {
+    /// Formats the value using the given formatter.
+    ///
+    /// ```rust
+    /// struct Id(u32);
+    ///
+    /// impl stylish::Debug for Id {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.write_str("#")?;
+    ///         stylish::Debug::fmt(&self.0, f)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     stylish::html::format!("{:s?}", Id(5)),
+    ///     "#<span style=color:cyan>5</span>",
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result;
+}
+
+/// The parts of the debug output that are highlighted, see [`Debug`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Highlight {
+    Number,
+    String,
+    Keyword,
+    Punctuation,
+}
+
+impl Highlight {
+    fn class(self) -> &'static str {
+        match self {
+            Self::Number => "debug.number",
+            Self::String => "debug.string",
+            Self::Keyword => "debug.keyword",
+            Self::Punctuation => "debug.punctuation",
+        }
+    }
+
+    fn default_style(self) -> StyleDiff {
+        match self {
+            Self::Number => StyleDiff::new().foreground(Color::Cyan),
+            Self::String => StyleDiff::new().foreground(Color::Green),
+            Self::Keyword => StyleDiff::new().foreground(Color::Magenta),
+            Self::Punctuation => StyleDiff::new(),
+        }
+    }
+
+    /// The style changes for this part, from the current theme if it styles
+    /// it.
+    pub(crate) fn style(self, f: &Formatter<'_>) -> StyleDiff {
+        f.theme.get(self.class()).unwrap_or(self.default_style())
+    }
+}
+
+/// Writes `value` using its [`core::fmt::Debug`] implementation, which applies
+/// the formatting arguments the same way as `{:?}`, highlighted as `highlight`.
+fn std_debug(
+    f: &mut Formatter<'_>,
+    highlight: Highlight,
+    value: &(dyn core::fmt::Debug + '_),
+) -> Result {
+    let style = highlight.style(f);
+    Display::fmt(
+        &StdFmtDebug(StdFmt {
+            f: &|f| value.fmt(f),
+        }),
+        &mut f.with(style),
+    )
+}
+
+impl<T: Debug + ?Sized> Debug for &T {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: Debug + ?Sized> Debug for &mut T {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        (**self).fmt(f)
+    }
+}
+
+macro_rules! std_debug {
+    ($highlight:ident: $($ty:ty),*) => {
+        $(
+            impl Debug for $ty {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                    std_debug(f, Highlight::$highlight, &self)
+                }
+            }
+        )*
+    };
+}
+
+std_debug!(Number: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+std_debug!(String: char, str);
+std_debug!(Keyword: bool);
+
+#[cfg(feature = "alloc")]
+std_debug!(String: alloc::string::String);
+
+impl Debug for () {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let style = Highlight::Punctuation.style(f);
+        f.with(style).pad("()")
+    }
+}
+
+impl<T: Debug> Debug for Option<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Some(value) => f.debug_variant("Some").field(value).finish(),
+            None => {
+                let style = Highlight::Keyword.style(f);
+                f.with(style).write_str("None")
+            }
+        }
+    }
+}
+
+impl<T: Debug, E: Debug> Debug for core::result::Result<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Ok(value) => f.debug_variant("Ok").field(value).finish(),
+            Err(error) => f.debug_variant("Err").field(error).finish(),
+        }
+    }
+}
+
+macro_rules! tuple {
+    ($($name:ident)+) => {
+        impl<$($name: Debug),+> Debug for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                let ($($name,)+) = self;
+                f.debug_tuple("")$(.field($name))+.finish()
+            }
+        }
+    };
+}
+
+tuple!(A);
+tuple!(A B);
+tuple!(A B C);
+tuple!(A B C D);
+tuple!(A B C D E);
+tuple!(A B C D E F);
+tuple!(A B C D E F G);
+tuple!(A B C D E F G H);
+tuple!(A B C D E F G H I);
+tuple!(A B C D E F G H I J);
+tuple!(A B C D E F G H I J K);
+tuple!(A B C D E F G H I J K L);
+
+impl<T: Debug> Debug for [T] {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: Debug, const N: usize> Debug for [T; N] {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(&self[..], f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Debug + ?Sized> Debug for alloc::boxed::Box<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Debug> Debug for alloc::vec::Vec<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(&self[..], f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Debug, V: Debug> Debug for alloc::collections::BTreeMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Debug> Debug for alloc::collections::BTreeSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_set().entries(self).finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Debug, V: Debug, S> Debug for std::collections::HashMap<K, V, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_map().entries(self).finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Debug, S> Debug for std::collections::HashSet<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_set().entries(self).finish()
+    }
+}
+
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+/// pub for macros, selects between [`StylishDebug`] and [`StdDebug`] for
+/// `{:s?}` arguments by whether the value implements [`Debug`]
+pub struct DebugArg<'a, T: ?Sized>(
+    #[doc(hidden)]
+    /// pub for macros
+    pub &'a T,
+);
+
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+/// pub for macros
+pub struct StylishDebug<'a, T: ?Sized>(&'a T);
+
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+/// pub for macros
+pub struct StdDebug<'a, T: ?Sized>(&'a T);
+
+impl<T: Debug + ?Sized> Display for StylishDebug<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Debug::fmt(self.0, f)
+    }
+}
+
+impl<T: core::fmt::Debug + ?Sized> Display for StdDebug<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let value = self.0;
+        Display::fmt(
+            &StdFmtDebug(StdFmt {
+                f: &|f| value.fmt(f),
+            }),
+            f,
+        )
+    }
+}
+
+#[doc(hidden)]
+/// pub for macros, picked first by method resolution
+pub trait ViaStylishDebug<'a, T: ?Sized> {
+    #[doc(hidden)]
+    /// pub for macros
+    fn __stylish_debug(&self) -> StylishDebug<'a, T>;
+}
+
+impl<'a, T: Debug + ?Sized> ViaStylishDebug<'a, T> for DebugArg<'a, T> {
+    #[inline]
+    fn __stylish_debug(&self) -> StylishDebug<'a, T> {
+        StylishDebug(self.0)
+    }
+}
+
+#[doc(hidden)]
+/// pub for macros, picked by method resolution when [`ViaStylishDebug`] is not
+/// implemented as it needs an extra reference
+pub trait ViaStdDebug<'a, T: ?Sized> {
+    #[doc(hidden)]
+    /// pub for macros
+    fn __stylish_debug(&self) -> StdDebug<'a, T>;
+}
+
+impl<'a, T: core::fmt::Debug + ?Sized> ViaStdDebug<'a, T> for &DebugArg<'a, T> {
+    #[inline]
+    fn __stylish_debug(&self) -> StdDebug<'a, T> {
+        StdDebug(self.0)
+    }
+}
@@ -1,4 +1,11 @@
-use crate::{Arguments, Display, Restyle, Result, Style, Write};
+use core::cell::Cell;
+
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::{
+    debug::Highlight, Arguments, DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple, Display,
+    Restyle, Result, Style, StyleDiff, Theme, Write,
+};
 
 #[doc(hidden)] // workaround https://github.com/rust-lang/rust/issues/85522
 #[derive(Clone, Copy, Debug)]
@@ -23,8 +30,9 @@ pub enum DebugHex {
 }
 
 #[doc(hidden)] // workaround https://github.com/rust-lang/rust/issues/85522
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug)]
 pub struct FormatterArgs<'a> {
+    pub fill: char,
     pub align: Option<Align>,
     pub sign: Option<Sign>,
     pub alternate: bool,
@@ -34,6 +42,96 @@ pub struct FormatterArgs<'a> {
     pub debug_hex: Option<DebugHex>,
 }
 
+impl Default for FormatterArgs<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            align: None,
+            sign: None,
+            alternate: false,
+            zero: false,
+            width: None,
+            precision: None,
+            debug_hex: None,
+        }
+    }
+}
+
+/// Measures the width of the output written to it, instead of writing it.
+struct Measure {
+    width: usize,
+    width_of: fn(&str) -> usize,
+}
+
+impl Write for Measure {
+    #[inline]
+    fn write_str(&mut self, s: &str, _style: Style) -> Result {
+        self.width += (self.width_of)(s);
+        Ok(())
+    }
+}
+
+/// The length of the longest prefix of `s` that is at most `max` columns
+/// wide, along with its width.
+fn split_at_width(s: &str, max: usize) -> (usize, usize) {
+    let mut width = 0;
+    for (i, c) in s.char_indices() {
+        let next = width + c.width().unwrap_or(0);
+        if next > max {
+            return (i, width);
+        }
+        width = next;
+    }
+    (s.len(), width)
+}
+
+/// Which of the width and precision an implementation marked as handled while
+/// being formatted, meaning it applies them itself so [`Formatter::padded`]
+/// should not.
+#[derive(Debug, Default)]
+pub(crate) struct HandledArgs {
+    width: Cell<bool>,
+    precision: Cell<bool>,
+}
+
+/// Passes output through to `write` until it reaches `remaining` columns
+/// wide, dropping the rest.
+struct Truncate<W> {
+    write: W,
+    remaining: Option<usize>,
+    truncated: bool,
+}
+
+impl<W: Write> Write for Truncate<W> {
+    fn write_str(&mut self, s: &str, style: Style) -> Result {
+        let Some(remaining) = &mut self.remaining else {
+            return self.write.write_str(s, style);
+        };
+        if self.truncated {
+            return Ok(());
+        }
+
+        let (end, width) = split_at_width(s, *remaining);
+        *remaining -= width;
+        self.truncated = end < s.len();
+        if end > 0 {
+            self.write.write_str(&s[..end], style)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result {
+        self.write.start_link(url)
+    }
+
+    #[inline]
+    fn end_link(&mut self, url: &str) -> Result {
+        self.write.end_link(url)
+    }
+}
+
 /// A configured output stream.
 ///
 /// A `Formatter` wraps a target output stream with a set of configuration
@@ -42,9 +140,12 @@ pub struct FormatterArgs<'a> {
 /// implementations of [`stylish::Display`] when they are used in
 /// the [`stylish`] macros.
 pub struct Formatter<'a> {
-    style: Style,
+    pub(crate) style: Style,
     pub(crate) format: FormatterArgs<'a>,
-    write: &'a mut (dyn Write + 'a),
+    pub(crate) linked: bool,
+    pub(crate) theme: &'a (dyn Theme + 'a),
+    pub(crate) write: &'a mut (dyn Write + 'a),
+    pub(crate) handled_args: Option<&'a HandledArgs>,
 }
 
 impl core::fmt::Debug for Formatter<'_> {
@@ -53,6 +154,7 @@ impl core::fmt::Debug for Formatter<'_> {
         f.debug_struct("Formatter")
             .field("style", &self.style)
             .field("format", &self.format)
+            .field("linked", &self.linked)
             .finish()
     }
 }
@@ -63,12 +165,13 @@ impl<'a> Formatter<'a> {
         Self {
             style: Style::default(),
             format: FormatterArgs::default(),
+            linked: false,
+            theme: &(),
             write,
+            handled_args: None,
         }
     }
 
-    // TODO: All the rest of the std::fmt::Formatter methods
-
     /// Create a sub-`Formatter` with some styles changed. This may be useful in
     /// implementations of [`stylish::Display`] to dynamically configure how
     /// some parts are formatted.
@@ -97,7 +200,75 @@ impl<'a> Formatter<'a> {
         Formatter {
             write: &mut *self.write,
             format: self.format,
+            linked: self.linked,
+            theme: self.theme,
             style: self.style.with(restyle),
+            handled_args: self.handled_args,
+        }
+    }
+
+    /// Create a sub-`Formatter` with the styles for `class` from the current
+    /// [`Theme`] applied. If the theme does not style `class` then the
+    /// styles are unchanged.
+    ///
+    /// ```rust
+    /// struct Path(&'static str);
+    ///
+    /// impl stylish::Display for Path {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.with_class("path").write_str(self.0)
+    ///     }
+    /// }
+    ///
+    /// let theme = [("path", "fg=cyan".parse()?)];
+    ///
+    /// let mut writer = stylish::Themed::new(stylish::html(String::new()), theme);
+    /// stylish::write!(writer, "reading {:s}", Path("Cargo.toml"))?;
+    /// assert_eq!(
+    ///     writer.into_inner().finish()?,
+    ///     "reading <span style=color:cyan>Cargo.toml</span>",
+    /// );
+    ///
+    /// assert_eq!(
+    ///     stylish::html::format!("reading {:s}", Path("Cargo.toml")),
+    ///     "reading Cargo.toml",
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_class(&mut self, class: &str) -> Formatter<'_> {
+        let diff = self.theme.get(class);
+        self.with(diff)
+    }
+
+    /// Create a sub-`Formatter` that resolves classes using `theme` instead
+    /// of the current [`Theme`].
+    ///
+    /// ```rust
+    /// use stylish::Formatter;
+    ///
+    /// struct Diagnostic(&'static str);
+    ///
+    /// impl stylish::Display for Diagnostic {
+    ///     fn fmt(&self, f: &mut Formatter<'_>) -> stylish::Result {
+    ///         let theme = [("error", "fg=red,bold".parse().unwrap())];
+    ///         f.with_theme(&theme)
+    ///             .write_fmt(stylish::format_args!("{:(.error)}: {}", "error", self.0))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     stylish::html::format!("{:s}", Diagnostic("oops")),
+    ///     "<span style=color:red;font-weight:bolder>error</span>: oops",
+    /// );
+    /// ```
+    pub fn with_theme<'b>(&'b mut self, theme: &'b (dyn Theme + 'b)) -> Formatter<'b> {
+        Formatter {
+            write: &mut *self.write,
+            format: self.format,
+            linked: self.linked,
+            theme,
+            style: self.style,
+            handled_args: self.handled_args,
         }
     }
 
@@ -111,8 +282,178 @@ impl<'a> Formatter<'a> {
         Formatter {
             write: &mut *self.write,
             format: *format,
+            linked: self.linked,
+            theme: self.theme,
             style: self.style.with(restyle),
+            handled_args: None,
+        }
+    }
+
+    /// Create a sub-`Formatter` with the styles for each of `classes` from
+    /// the current [`Theme`] applied, and then `restyle`.
+    pub(crate) fn with_classes(
+        &mut self,
+        classes: &[&str],
+        restyle: impl Restyle,
+    ) -> Formatter<'_> {
+        let style = classes
+            .iter()
+            .fold(self.style, |style, class| style.with(self.theme.get(class)));
+        Formatter {
+            write: &mut *self.write,
+            format: self.format,
+            linked: self.linked,
+            theme: self.theme,
+            style: style.with(restyle),
+            handled_args: self.handled_args,
+        }
+    }
+
+    /// Writes `padding` fill characters in the current style around the
+    /// output of `content`, placed according to the alignment in the
+    /// formatting arguments or `default_align` if there is none.
+    fn write_padded(
+        &mut self,
+        padding: usize,
+        default_align: Align,
+        content: impl FnOnce(&mut Self) -> Result,
+    ) -> Result {
+        let (before, after) = match self.format.align.unwrap_or(default_align) {
+            Align::Left => (0, padding),
+            Align::Center => (padding / 2, padding.div_ceil(2)),
+            Align::Right => (padding, 0),
+        };
+        for _ in 0..before {
+            self.write.write_char(self.format.fill, self.style)?;
+        }
+        content(self)?;
+        for _ in 0..after {
+            self.write.write_char(self.format.fill, self.style)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the output of `content` given the formatting arguments
+    /// `format`, truncated to `precision` columns and padded to the width in
+    /// the current formatting arguments, measuring the output with
+    /// `width_of`.
+    ///
+    /// With the `alloc` feature `content` is called once, writing into a
+    /// buffer that is then measured and written from. Without it `content` is
+    /// instead called again for each pass, so implementations with side
+    /// effects or output that varies between calls may be padded incorrectly.
+    ///
+    /// If `content` marks the width or precision as handled (e.g. by calling
+    /// [`Formatter::pad`]) then that argument is not applied again here.
+    pub(crate) fn padded(
+        &mut self,
+        width_of: fn(&str) -> usize,
+        precision: Option<usize>,
+        format: FormatterArgs<'a>,
+        default_align: Align,
+        content: &dyn Fn(&mut Formatter<'_>) -> Result,
+    ) -> Result {
+        let Self {
+            style,
+            linked,
+            theme,
+            ..
+        } = *self;
+        let content = |write: &mut dyn Write, remaining, handled_args| {
+            content(&mut Formatter {
+                style,
+                format,
+                linked,
+                theme,
+                write: &mut Truncate {
+                    write,
+                    remaining,
+                    truncated: false,
+                },
+                handled_args,
+            })
+        };
+
+        let handled_args = HandledArgs::default();
+        #[cfg(feature = "alloc")]
+        let content = {
+            let mut buffer = crate::String::new();
+            content(&mut buffer, None, Some(&handled_args))?;
+            move |write: &mut dyn Write, remaining, _: Option<&HandledArgs>| {
+                Display::fmt(
+                    &buffer,
+                    &mut Formatter::new(&mut Truncate {
+                        write,
+                        remaining,
+                        truncated: false,
+                    }),
+                )
+            }
+        };
+
+        let mut measure = Measure { width: 0, width_of };
+        content(&mut measure, None, Some(&handled_args))?;
+        let precision = precision.filter(|_| !handled_args.precision.get());
+        let Some(&width) = self.format.width.filter(|_| !handled_args.width.get()) else {
+            return content(&mut *self.write, precision, None);
+        };
+        if precision.is_some() {
+            measure.width = 0;
+            content(&mut measure, precision, None)?;
+        }
+
+        let padding = width.saturating_sub(measure.width);
+        self.write_padded(padding, default_align, |f| {
+            content(&mut *f.write, precision, None)
+        })
+    }
+
+    /// Writes the content produced by `content` as a hyperlink to `url`.
+    ///
+    /// How the link is shown depends on the output stream, e.g. `stylish::Ansi`
+    /// emits an OSC 8 escape sequence while `stylish::Html` wraps the content
+    /// in an `<a>` element. Links cannot be nested, if this is called while
+    /// already writing the content of a link then only the content is written.
+    ///
+    /// ```rust
+    /// struct Code(&'static str);
+    ///
+    /// impl stylish::Display for Code {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         let url = format!("https://example.com/errors/{}", self.0);
+    ///         f.link(&url, |f| {
+    ///             f.with(stylish::Foreground(stylish::Color::Red))
+    ///                 .write_str(self.0)
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let formatted = stylish::html::format!("error[{:s}]", Code("E0001"));
+    /// assert_eq!(
+    ///     formatted,
+    ///     "error[<a href=\"https://example.com/errors/E0001\">\
+    ///         <span style=color:red>E0001</span>\
+    ///     </a>]"
+    /// );
+    /// ```
+    pub fn link(
+        &mut self,
+        url: &str,
+        content: impl FnOnce(&mut Formatter<'_>) -> Result,
+    ) -> Result {
+        if self.linked {
+            return content(self);
         }
+        self.write.start_link(url)?;
+        content(&mut Formatter {
+            write: &mut *self.write,
+            format: self.format,
+            linked: true,
+            theme: self.theme,
+            style: self.style,
+            handled_args: self.handled_args,
+        })?;
+        self.write.end_link(url)
     }
 
     /// Writes some data to the underlying output stream, using the current
@@ -171,6 +512,348 @@ impl<'a> Formatter<'a> {
         args.fmt(self)?;
         Ok(())
     }
+
+    /// The style that data is currently written in.
+    ///
+    /// ```rust
+    /// use stylish::{Color, Foreground, Style};
+    ///
+    /// struct Current;
+    ///
+    /// impl stylish::Display for Current {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         assert_eq!(f.style(), Style::default().with(Foreground(Color::Red)));
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// stylish::html::format!("{:(fg=red)s}", Current);
+    /// ```
+    #[inline]
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// The character used to pad output to the [`width`](Formatter::width),
+    /// a space by default.
+    #[inline]
+    pub fn fill(&self) -> char {
+        self.format.fill
+    }
+
+    /// The requested alignment of padded output, if any.
+    #[inline]
+    pub fn align(&self) -> Option<core::fmt::Alignment> {
+        self.format.align.map(|align| match align {
+            Align::Left => core::fmt::Alignment::Left,
+            Align::Center => core::fmt::Alignment::Center,
+            Align::Right => core::fmt::Alignment::Right,
+        })
+    }
+
+    /// The minimum width the output should be padded to, if any.
+    ///
+    /// Arguments formatted with `{:s}` are padded to this automatically,
+    /// reading it does not change that. Implementations that apply the width
+    /// themselves must call [`Formatter::handles_width`] (or use
+    /// [`Formatter::pad`] which does so).
+    ///
+    /// ```rust
+    /// struct Count(usize);
+    ///
+    /// impl stylish::Display for Count {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.handles_width();
+    ///         let width = f.width().unwrap_or(0);
+    ///         let dots = ".".repeat(self.0);
+    ///         f.with(stylish::Intensity::Bold).write_str(&format!("{dots:>width$}"))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     stylish::html::format!("[{:*>5s}]", Count(2)),
+    ///     "[<span style=font-weight:bolder>   ..</span>]",
+    /// );
+    /// ```
+    #[inline]
+    pub fn width(&self) -> Option<usize> {
+        self.format.width.copied()
+    }
+
+    /// The precision requested for the output, if any. For text this is the
+    /// maximum width, while numeric types use it for the number of digits
+    /// after the decimal point.
+    ///
+    /// Arguments formatted with `{:s}` are truncated to this automatically,
+    /// reading it does not change that. Implementations that use it for
+    /// something else must call [`Formatter::handles_precision`].
+    ///
+    /// ```rust
+    /// struct Meters(f64);
+    ///
+    /// impl stylish::Display for Meters {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.handles_precision();
+    ///         let precision = f.precision().unwrap_or(1);
+    ///         f.write_str(&format!("{:.*}m", precision, self.0))
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(stylish::plain::format!("{:>8.2s}", Meters(1.5)), "   1.50m");
+    /// ```
+    #[inline]
+    pub fn precision(&self) -> Option<usize> {
+        self.format.precision.copied()
+    }
+
+    /// Marks that this implementation pads its output to the
+    /// [`width`](Formatter::width) itself, so an argument formatted with
+    /// `{:s}` is not padded again afterwards.
+    #[inline]
+    pub fn handles_width(&self) {
+        if let Some(handled_args) = self.handled_args {
+            handled_args.width.set(true);
+        }
+    }
+
+    /// Marks that this implementation applies the
+    /// [`precision`](Formatter::precision) itself, so the output of an
+    /// argument formatted with `{:s}` is not truncated to it.
+    #[inline]
+    pub fn handles_precision(&self) {
+        if let Some(handled_args) = self.handled_args {
+            handled_args.precision.set(true);
+        }
+    }
+
+    /// Whether the `+` flag was given.
+    #[inline]
+    pub fn sign_plus(&self) -> bool {
+        matches!(self.format.sign, Some(Sign::Plus))
+    }
+
+    /// Whether the `-` flag was given.
+    #[inline]
+    pub fn sign_minus(&self) -> bool {
+        matches!(self.format.sign, Some(Sign::Minus))
+    }
+
+    /// Whether the `#` flag was given.
+    #[inline]
+    pub fn alternate(&self) -> bool {
+        self.format.alternate
+    }
+
+    /// Whether the `0` flag was given.
+    #[inline]
+    pub fn sign_aware_zero_pad(&self) -> bool {
+        self.format.zero
+    }
+
+    /// Writes `s` truncated to the [`precision`](Formatter::precision) and
+    /// padded to the [`width`](Formatter::width), like
+    /// [`core::fmt::Formatter::pad`], in the current style.
+    ///
+    /// The text is measured by its display width, and is aligned to the left
+    /// by default.
+    ///
+    /// ```rust
+    /// struct Tag(&'static str);
+    ///
+    /// impl stylish::Display for Tag {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.with(stylish::Foreground(stylish::Color::Blue)).pad(self.0)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     stylish::html::format!("[{:^7.3s}]", Tag("release")),
+    ///     "[<span style=color:blue>  rel  </span>]",
+    /// );
+    /// ```
+    pub fn pad(&mut self, s: &str) -> Result {
+        self.handles_width();
+        self.handles_precision();
+        let (s, width) = match self.precision() {
+            Some(precision) => {
+                let (end, width) = split_at_width(s, precision);
+                (&s[..end], width)
+            }
+            None => (s, s.width()),
+        };
+        let padding = self.width().map_or(0, |min| min.saturating_sub(width));
+        self.write_padded(padding, Align::Left, |f| f.write_str(s))
+    }
+
+    /// Writes an integer that has already been converted to the digits in
+    /// `buf`, handling the sign, the `prefix` for the `#` flag, and padding
+    /// like [`core::fmt::Formatter::pad_integral`], in the current style.
+    ///
+    /// ```rust
+    /// struct Hex(i32);
+    ///
+    /// impl stylish::Display for Hex {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         let digits = format!("{:x}", self.0.unsigned_abs());
+    ///         f.with(stylish::Foreground(stylish::Color::Cyan))
+    ///             .pad_integral(self.0 >= 0, "0x", &digits)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     stylish::html::format!("{:#08s} {:+s}", Hex(-255), Hex(3)),
+    ///     "<span style=color:cyan>-0x000ff</span> <span style=color:cyan>+3</span>",
+    /// );
+    /// ```
+    pub fn pad_integral(&mut self, is_nonnegative: bool, prefix: &str, buf: &str) -> Result {
+        let sign = match (is_nonnegative, self.sign_plus()) {
+            (false, _) => "-",
+            (true, true) => "+",
+            (true, false) => "",
+        };
+        let prefix = if self.alternate() { prefix } else { "" };
+        // Like std the precision does not apply to integers, but the digits
+        // must not be truncated to it either
+        self.handles_width();
+        self.handles_precision();
+        let width = sign.len() + prefix.width() + buf.width();
+        let padding = self.width().map_or(0, |min| min.saturating_sub(width));
+
+        if self.sign_aware_zero_pad() {
+            self.write_str(sign)?;
+            self.write_str(prefix)?;
+            for _ in 0..padding {
+                self.write_str("0")?;
+            }
+            self.write_str(buf)
+        } else {
+            self.write_padded(padding, Align::Right, |f| {
+                f.write_str(sign)?;
+                f.write_str(prefix)?;
+                f.write_str(buf)
+            })
+        }
+    }
+
+    /// Creates a [`DebugStruct`] builder designed to assist with creating
+    /// [`stylish::Debug`] implementations for structs, including the `{:#s?}`
+    /// pretty-printed format.
+    ///
+    /// ```rust
+    /// struct Foo {
+    ///     bar: i32,
+    ///     baz: String,
+    /// }
+    ///
+    /// impl stylish::Debug for Foo {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.debug_struct("Foo")
+    ///             .field("bar", &self.bar)
+    ///             .field("baz", &self.baz)
+    ///             .finish()
+    ///     }
+    /// }
+    ///
+    /// let foo = Foo { bar: 10, baz: "Hello World".into() };
+    /// assert_eq!(
+    ///     stylish::plain::format!("{:s?}", foo),
+    ///     "Foo { bar: 10, baz: \"Hello World\" }",
+    /// );
+    /// assert_eq!(
+    ///     stylish::plain::format!("{:#s?}", foo),
+    ///     "Foo {\n    bar: 10,\n    baz: \"Hello World\",\n}",
+    /// );
+    /// ```
+    pub fn debug_struct<'b>(&'b mut self, name: &str) -> DebugStruct<'b, 'a> {
+        DebugStruct::new(self, name)
+    }
+
+    /// Creates a [`DebugTuple`] builder designed to assist with creating
+    /// [`stylish::Debug`] implementations for tuple structs.
+    ///
+    /// ```rust
+    /// struct Foo(i32, String);
+    ///
+    /// impl stylish::Debug for Foo {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.debug_tuple("Foo").field(&self.0).field(&self.1).finish()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     stylish::plain::format!("{:s?}", Foo(10, "Hello".into())),
+    ///     "Foo(10, \"Hello\")",
+    /// );
+    /// ```
+    pub fn debug_tuple<'b>(&'b mut self, name: &str) -> DebugTuple<'b, 'a> {
+        DebugTuple::new(self, name, StyleDiff::new())
+    }
+
+    /// Creates a [`DebugTuple`] for an enum variant, with the name highlighted
+    /// as a keyword.
+    pub(crate) fn debug_variant<'b>(&'b mut self, name: &str) -> DebugTuple<'b, 'a> {
+        let style = Highlight::Keyword.style(self);
+        DebugTuple::new(self, name, style)
+    }
+
+    /// Creates a [`DebugList`] builder designed to assist with creating
+    /// [`stylish::Debug`] implementations for list-like structures.
+    ///
+    /// ```rust
+    /// struct Foo(Vec<i32>);
+    ///
+    /// impl stylish::Debug for Foo {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.debug_list().entries(self.0.iter()).finish()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(stylish::plain::format!("{:s?}", Foo(vec![10, 11])), "[10, 11]");
+    /// ```
+    pub fn debug_list<'b>(&'b mut self) -> DebugList<'b, 'a> {
+        DebugList::new(self)
+    }
+
+    /// Creates a [`DebugSet`] builder designed to assist with creating
+    /// [`stylish::Debug`] implementations for set-like structures.
+    ///
+    /// ```rust
+    /// struct Foo(Vec<i32>);
+    ///
+    /// impl stylish::Debug for Foo {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.debug_set().entries(self.0.iter()).finish()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(stylish::plain::format!("{:s?}", Foo(vec![10, 11])), "{10, 11}");
+    /// ```
+    pub fn debug_set<'b>(&'b mut self) -> DebugSet<'b, 'a> {
+        DebugSet::new(self)
+    }
+
+    /// Creates a [`DebugMap`] builder designed to assist with creating
+    /// [`stylish::Debug`] implementations for map-like structures.
+    ///
+    /// ```rust
+    /// struct Foo(Vec<(String, i32)>);
+    ///
+    /// impl stylish::Debug for Foo {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.debug_map()
+    ///             .entries(self.0.iter().map(|(k, v)| (k, v)))
+    ///             .finish()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     stylish::plain::format!("{:s?}", Foo(vec![("A".into(), 10), ("B".into(), 11)])),
+    ///     "{\"A\": 10, \"B\": 11}",
+    /// );
+    /// ```
+    pub fn debug_map<'b>(&'b mut self) -> DebugMap<'b, 'a> {
+        DebugMap::new(self)
+    }
 }
 
 impl<'a> Write for Formatter<'a> {
@@ -183,6 +866,16 @@ impl<'a> Write for Formatter<'a> {
     fn write_fmt(&mut self, args: Arguments<'_>) -> Result {
         self.write_fmt(args)
     }
+
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result {
+        self.write.start_link(url)
+    }
+
+    #[inline]
+    fn end_link(&mut self, url: &str) -> Result {
+        self.write.end_link(url)
+    }
 }
 
 impl<'a> core::fmt::Write for Formatter<'a> {
@@ -0,0 +1,139 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{Background, Color, Display, Foreground, Formatter, Oklch, Result};
+
+#[derive(Copy, Clone, Debug)]
+enum Stops<'a> {
+    Colors(&'a [Color]),
+    Rainbow,
+}
+
+/// A [`Display`] wrapper that colors each grapheme of some text along a
+/// gradient between two or more colors.
+///
+/// Colors are interpolated in the perceptually uniform Oklab color space (see
+/// [`Color::interpolate`]) and written as [`Color::Rgb`], writers that don't
+/// support truecolor should downsample them to the nearest supported color
+/// with a [`ColorDepth`](crate::ColorDepth). Any other attributes of the
+/// current style are kept.
+///
+/// ```rust
+/// use stylish::{Color, ColorDepth, Gradient};
+///
+/// let colors = [Color::Rgb(0xff, 0x00, 0x00), Color::Rgb(0x00, 0x00, 0xff)];
+///
+/// assert_eq!(
+///     stylish::html::format!("{:s}", Gradient::new("abc", &colors)),
+///     "<span style=color:#ff0000>a</span>\
+///      <span style=color:#8c53a2>b</span>\
+///      <span style=color:#0000ff>c</span>",
+/// );
+///
+/// let mut writer = stylish::Ansi::new(String::new()).depth(ColorDepth::Ansi16);
+/// stylish::write!(writer, "{:(bold)s}", Gradient::new("abc", &colors))?;
+/// assert_eq!(
+///     writer.finish()?,
+///     "\x1b[91;1ma\x1b[90mb\x1b[34mc\x1b[0m",
+/// );
+/// # Ok::<(), core::fmt::Error>(())
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Gradient<'a> {
+    text: &'a str,
+    stops: Stops<'a>,
+    background: bool,
+}
+
+impl<'a> Gradient<'a> {
+    /// Color `text` with a gradient passing evenly through `colors`, from the
+    /// first color on the first grapheme to the last color on the last.
+    ///
+    /// With a single color all of the text is that color, with no colors the
+    /// text is written unchanged.
+    pub fn new(text: &'a str, colors: &'a [Color]) -> Self {
+        Self {
+            text,
+            stops: Stops::Colors(colors),
+            background: false,
+        }
+    }
+
+    /// Color `text` with a rainbow, cycling once through all hues at a
+    /// constant perceived lightness.
+    ///
+    /// ```rust
+    /// let formatted = stylish::html::format!("{:s}", stylish::Gradient::rainbow("ab"));
+    /// assert_eq!(
+    ///     formatted,
+    ///     "<span style=color:#f982aa>a</span><span style=color:#00ccb2>b</span>",
+    /// );
+    /// ```
+    pub fn rainbow(text: &'a str) -> Self {
+        Self {
+            text,
+            stops: Stops::Rainbow,
+            background: false,
+        }
+    }
+
+    /// Whether to apply the gradient to the background instead of the
+    /// foreground.
+    ///
+    /// ```rust
+    /// use stylish::{Color, Gradient};
+    ///
+    /// let colors = [Color::Rgb(0x00, 0x00, 0x00), Color::Rgb(0xff, 0xff, 0xff)];
+    /// let formatted = stylish::html::format!(
+    ///     "{:s}",
+    ///     Gradient::new("ab", &colors).background(true),
+    /// );
+    /// assert_eq!(
+    ///     formatted,
+    ///     "<span style=background-color:#000000>a</span>\
+    ///      <span style=background-color:#ffffff>b</span>",
+    /// );
+    /// ```
+    pub fn background(self, background: bool) -> Self {
+        Self { background, ..self }
+    }
+
+    /// The color at `position`, from `0.0` (the start) to `1.0` (the end).
+    fn color(&self, position: f32) -> Option<Color> {
+        match self.stops {
+            Stops::Colors([]) => None,
+            Stops::Colors(&[color]) => Some(color),
+            Stops::Colors(colors) => {
+                let position = position * (colors.len() - 1) as f32;
+                // Position is in 0.0..=len-1 so the cast cannot truncate
+                let index = (position as usize).min(colors.len() - 2);
+                Some(colors[index].interpolate(colors[index + 1], position - index as f32))
+            }
+            Stops::Rainbow => Some(Color::from(Oklch {
+                lightness: 0.75,
+                chroma: 0.15,
+                hue: position * 360.0,
+            })),
+        }
+    }
+}
+
+impl Display for Gradient<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let count = self.text.graphemes(true).count();
+        // The rainbow wraps around so it shouldn't end on the starting hue
+        let steps = match self.stops {
+            Stops::Colors(_) => count.saturating_sub(1).max(1),
+            Stops::Rainbow => count,
+        };
+
+        for (index, grapheme) in self.text.graphemes(true).enumerate() {
+            match self.color(index as f32 / steps as f32) {
+                Some(color) if self.background => f.with(Background(color)).write_str(grapheme)?,
+                Some(color) => f.with(Foreground(color)).write_str(grapheme)?,
+                None => f.write_str(grapheme)?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@
 
 pub use std::io::{Error, ErrorKind, Result};
 
-use crate::{Arguments, Style};
+use crate::{Arguments, Formatter, Restyle, Style, Theme};
 
 struct ErrorTrap<W: Write> {
     inner: W,
@@ -18,13 +18,13 @@ impl<W: Write> ErrorTrap<W> {
     fn error(&mut self) -> Error {
         self.error
             .take()
-            .unwrap_or_else(|| Error::new(ErrorKind::Other, "formatter error"))
+            .unwrap_or_else(|| Error::other("formatter error"))
     }
 }
 
-impl<W: Write> crate::Write for ErrorTrap<W> {
-    fn write_str(&mut self, s: &str, style: Style) -> crate::Result {
-        match self.inner.write_all(s.as_bytes(), style) {
+impl<W: Write> ErrorTrap<W> {
+    fn trap(&mut self, result: Result<()>) -> crate::Result {
+        match result {
             Ok(()) => Ok(()),
             Err(err) => {
                 self.error = Some(err);
@@ -34,6 +34,23 @@ impl<W: Write> crate::Write for ErrorTrap<W> {
     }
 }
 
+impl<W: Write> crate::Write for ErrorTrap<W> {
+    fn write_str(&mut self, s: &str, style: Style) -> crate::Result {
+        let result = self.inner.write_all(s.as_bytes(), style);
+        self.trap(result)
+    }
+
+    fn start_link(&mut self, url: &str) -> crate::Result {
+        let result = self.inner.start_link(url);
+        self.trap(result)
+    }
+
+    fn end_link(&mut self, url: &str) -> crate::Result {
+        let result = self.inner.end_link(url);
+        self.trap(result)
+    }
+}
+
 /// A trait for objects which are byte-oriented sinks and can handle attributed
 /// data.
 ///
@@ -221,6 +238,55 @@ pub trait Write {
         crate::Write::write_fmt(&mut trap, args).map_err(|crate::Error| trap.error())
     }
 
+    /// Marks the start of a hyperlink to `url`, all data written until the
+    /// matching [`end_link`](Write::end_link) call is the content of the link.
+    ///
+    /// This method should generally not be invoked manually, but rather through
+    /// `stylish::Formatter::link` which ensures links are correctly ended and
+    /// not nested. The default implementation ignores the link and only the
+    /// content is written.
+    ///
+    /// # Errors
+    ///
+    /// This function will return any I/O error reported while writing the
+    /// start of the link.
+    ///
+    /// ```rust
+    /// use stylish::{io::Write, Style};
+    ///
+    /// let mut output = stylish::io::ansi(Vec::new());
+    ///
+    /// output.start_link("https://example.com")?;
+    /// output.write_all(b"docs", Style::default())?;
+    /// output.end_link("https://example.com")?;
+    ///
+    /// assert_eq!(
+    ///     output.finish()?,
+    ///     b"\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\",
+    /// );
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result<()> {
+        let _ = url;
+        Ok(())
+    }
+
+    /// Marks the end of a hyperlink started by [`start_link`](Write::start_link),
+    /// `url` is the same as was passed to that call.
+    ///
+    /// The default implementation does nothing.
+    ///
+    /// # Errors
+    ///
+    /// This function will return any I/O error reported while writing the end
+    /// of the link.
+    #[inline]
+    fn end_link(&mut self, url: &str) -> Result<()> {
+        let _ = url;
+        Ok(())
+    }
+
     /// Creates a "by reference" adaptor for this instance of `Write`.
     ///
     /// The returned adaptor also implements `Write` and will simply borrow this
@@ -267,4 +333,154 @@ impl<W: Write + ?Sized> Write for &mut W {
     fn write_fmt(&mut self, args: Arguments<'_>) -> Result<()> {
         (**self).write_fmt(args)
     }
+
+    fn start_link(&mut self, url: &str) -> Result<()> {
+        (**self).start_link(url)
+    }
+
+    fn end_link(&mut self, url: &str) -> Result<()> {
+        (**self).end_link(url)
+    }
+}
+
+/// An adaptor for a [`Write`] implementor that renders data written to it with
+/// a [`Theme`], resolving any classes used in the format strings.
+///
+/// ```rust
+/// use stylish::io::Themed;
+///
+/// let theme = [("path", "fg=cyan".parse()?)];
+///
+/// let mut writer = Themed::new(stylish::io::ansi(Vec::new()), theme);
+/// stylish::write!(writer, "reading {:(.path)}", "Cargo.toml")?;
+/// assert_eq!(
+///     writer.into_inner().finish()?,
+///     b"reading \x1b[36mCargo.toml\x1b[0m",
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Themed<W, T> {
+    inner: W,
+    theme: T,
+}
+
+impl<W: Write, T: Theme> Themed<W, T> {
+    /// Wrap the given output stream in this adaptor, rendering with `theme`.
+    pub fn new(inner: W, theme: T) -> Self {
+        Self { inner, theme }
+    }
+
+    /// Inherent delegation to [`Write::write_fmt`] to not require a trait
+    /// import.
+    pub fn write_fmt(&mut self, args: Arguments<'_>) -> Result<()> {
+        Write::write_fmt(self, args)
+    }
+
+    /// Get back the wrapped output stream.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, T: Theme> Write for Themed<W, T> {
+    #[inline]
+    fn write(&mut self, s: &[u8], style: Style) -> Result<usize> {
+        self.inner.write(s, style)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+
+    #[inline]
+    fn write_all(&mut self, s: &[u8], style: Style) -> Result<()> {
+        self.inner.write_all(s, style)
+    }
+
+    fn write_fmt(&mut self, args: Arguments<'_>) -> Result<()> {
+        let mut trap = ErrorTrap::new(&mut self.inner);
+
+        let result = Formatter::new(&mut trap)
+            .with_theme(&self.theme)
+            .write_fmt(args);
+        result.map_err(|crate::Error| trap.error())
+    }
+
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result<()> {
+        self.inner.start_link(url)
+    }
+
+    #[inline]
+    fn end_link(&mut self, url: &str) -> Result<()> {
+        self.inner.end_link(url)
+    }
+}
+
+/// An adaptor for a [`Write`] implementor that applies a [`Restyle`] to every
+/// style written through it, see [`stylish::Restyled`](crate::Restyled).
+///
+/// ```rust
+/// use stylish::{io::Restyled, Deficiency};
+///
+/// let mut writer = Restyled::new(stylish::io::ansi(Vec::new()), Deficiency::Protanopia);
+/// stylish::write!(writer, "{:(fg=#ff0000)}", "failed")?;
+/// assert_eq!(
+///     writer.into_inner().finish()?,
+///     b"\x1b[38;2;109;95;0mfailed\x1b[0m",
+/// );
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Restyled<W, R> {
+    inner: W,
+    restyle: R,
+}
+
+impl<W: Write, R: Restyle> Restyled<W, R> {
+    /// Wrap the given output stream in this adaptor, applying `restyle` to
+    /// every style written.
+    pub fn new(inner: W, restyle: R) -> Self {
+        Self { inner, restyle }
+    }
+
+    /// Inherent delegation to [`Write::write_fmt`] to not require a trait
+    /// import.
+    pub fn write_fmt(&mut self, args: Arguments<'_>) -> Result<()> {
+        Write::write_fmt(self, args)
+    }
+
+    /// Get back the wrapped output stream.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, R: Restyle> Write for Restyled<W, R> {
+    #[inline]
+    fn write(&mut self, s: &[u8], style: Style) -> Result<usize> {
+        self.inner.write(s, self.restyle.apply(style))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+
+    #[inline]
+    fn write_all(&mut self, s: &[u8], style: Style) -> Result<()> {
+        self.inner.write_all(s, self.restyle.apply(style))
+    }
+
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result<()> {
+        self.inner.start_link(url)
+    }
+
+    #[inline]
+    fn end_link(&mut self, url: &str) -> Result<()> {
+        self.inner.end_link(url)
+    }
 }
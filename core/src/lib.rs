@@ -25,23 +25,51 @@ pub mod io;
 mod std_compat;
 
 mod arguments;
+mod builders;
+mod debug;
 mod display;
 #[cfg(feature = "alloc")]
 mod format;
 mod formatter;
+#[cfg(feature = "gradient")]
+mod gradient;
+#[cfg(feature = "std")]
+mod ls_colors;
+mod restyled;
 #[cfg(feature = "alloc")]
 mod string;
+mod styled_str;
+mod theme;
 #[cfg(all(feature = "alloc", feature = "macros"))]
 mod to_string;
 mod write;
 
 pub use core::fmt::{Error, Result};
 
-pub use stylish_style::{Background, Color, Foreground, Intensity, Restyle, Style, StyleDiff};
+pub use stylish_style::{
+    Background, Blink, Color, ColorDepth, Deficiency, Foreground, Hidden, Hsl, Hsv, Intensity,
+    Italic, MinContrast, Oklch, Overline, Palette, ParseStyleError, ParseStyleErrorKind, Reset,
+    Restyle, RestyleFn, Reverse, SgrList, Strikethrough, Style, StyleDiff, Swap, Underline,
+    UnderlineColor, When,
+};
 
+#[cfg(feature = "gradient")]
+pub use self::gradient::Gradient;
+#[cfg(feature = "std")]
+pub use self::ls_colors::{LsColors, StyledPath};
 #[cfg(all(feature = "alloc", feature = "macros"))]
 pub use self::to_string::ToStylishString;
-pub use self::{arguments::Arguments, display::Display, formatter::Formatter, write::Write};
+pub use self::{
+    arguments::Arguments,
+    builders::{DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple},
+    debug::Debug,
+    display::Display,
+    formatter::Formatter,
+    restyled::Restyled,
+    styled_str::StyledStr,
+    theme::{Theme, Themed},
+    write::Write,
+};
 #[cfg(feature = "alloc")]
 pub use self::{format::format, string::String};
 
@@ -50,13 +78,14 @@ pub use self::{format::format, string::String};
 pub mod 𓀄 {
     pub use core::{fmt, option::Option};
 
-    pub use stylish_macros::{format_args, format_args_nl};
+    pub use stylish_macros::{format_args, format_args_nl, lit, style, style_diff};
     pub use with_builtin_macros::with_builtin;
 
     pub use crate::{
-        arguments::{Arguments, StdFmt, StdFmtDebug, StdFmtOther},
+        arguments::{Arguments, Padded, StdFmt, StdFmtDebug, StdFmtOther},
+        debug::{DebugArg, StdDebug, StylishDebug, ViaStdDebug, ViaStylishDebug},
         formatter::{Align, DebugHex, Formatter, FormatterArgs, Sign},
-        Background, Color, Display, Foreground, Intensity, StyleDiff,
+        Background, Color, Display, Foreground, Intensity, Style, StyleDiff, StyledStr, Underline,
     };
 }
 
@@ -87,6 +116,13 @@ pub mod 𓀄 {
 /// ));
 /// assert_eq!(s, stylish::html::format!("hello {:(fg=green)}", "world"));
 /// ```
+///
+/// Invalid format strings, style attributes and arguments are reported at
+/// compile time, pointing to the problem within the invocation where possible.
+///
+/// ```rust,compile_fail
+/// stylish::format_args!("hello {:(fg=greenish)}", "world");
+/// ```
 #[macro_export]
 macro_rules! format_args {
     ($fmt:literal $(, $($arg:tt)*)?) => {
@@ -121,3 +157,95 @@ macro_rules! _format_args {
         $crate::𓀄::format_args!(crate=$crate, $fmt $(, $($arg)*)?)
     };
 }
+
+#[cfg(feature = "macros")]
+/// Constructs a [`Style`] from a list of attributes, using the same syntax as
+/// attributes within a format string (without theme classes).
+///
+/// The attributes are validated at compile time and the result is a constant
+/// expression built with [`Style::new`] and its builder methods, so it can be
+/// used to initialize `const` and `static` items. The attributes may also be
+/// given as a string literal.
+///
+/// ```rust
+/// use stylish::{Color, Foreground, Intensity, Style};
+///
+/// const ERROR: Style = stylish::style!(fg=red, bold);
+/// assert_eq!(
+///     ERROR,
+///     Style::default().with(Foreground(Color::Red)).with(Intensity::Bold),
+/// );
+///
+/// assert_eq!(stylish::style!(fg=#ff8800, underline=curly), "fg=#ff8800,underline=curly".parse()?);
+/// assert_eq!(stylish::style!("bg=bright-blue"), "bg=bright-blue".parse()?);
+/// # Ok::<(), stylish::ParseStyleError>(())
+/// ```
+///
+/// ```rust,compile_fail
+/// const ERROR: stylish::Style = stylish::style!(fg=reddish);
+/// ```
+#[macro_export]
+macro_rules! style {
+    ($($attributes:tt)*) => {
+        $crate::𓀄::style!(crate=$crate, $($attributes)*)
+    };
+}
+
+#[cfg(feature = "macros")]
+/// Constructs a [`StyleDiff`] from a list of attributes, using the same syntax
+/// as attributes within a format string (without theme classes).
+///
+/// Like [`style!`] the attributes are validated at compile time and the result
+/// is a constant expression, built with [`StyleDiff::new`] and its builder
+/// methods.
+///
+/// ```rust
+/// use stylish::{Color, StyleDiff};
+///
+/// static WARNING: StyleDiff = stylish::style_diff!(fg=yellow, italic=false);
+/// assert_eq!(WARNING.foreground, Some(Color::Yellow));
+/// assert_eq!(WARNING.italic, Some(false));
+/// assert_eq!(WARNING.background, None);
+/// ```
+#[macro_export]
+macro_rules! style_diff {
+    ($($attributes:tt)*) => {
+        $crate::𓀄::style_diff!(crate=$crate, $($attributes)*)
+    };
+}
+
+#[cfg(feature = "macros")]
+/// Constructs a [`StyledStr`] at compile time from a format string, so that
+/// writing constant styled output requires no formatting at runtime.
+///
+/// This takes the same format string syntax as [`format_args!`], but the
+/// arguments must be literals (strings, characters, integers or booleans) and
+/// the only format options allowed are style attributes (without theme
+/// classes). The result is a `StyledStr<'static>` constant expression, so can
+/// also be used to initialize `const` and `static` items.
+///
+/// ```rust
+/// use stylish::StyledStr;
+///
+/// const ERROR: StyledStr<'static> = stylish::lit!("{:(fg=red,bold)}: ", "error");
+///
+/// assert_eq!(
+///     stylish::ansi::format!("{:s}file not found", ERROR),
+///     "\x1b[31;1merror\x1b[0m: file not found",
+/// );
+/// assert_eq!(
+///     stylish::lit!("{}{:(fg=red)} {n:(italic)}", "hello", '!', n = 42),
+///     stylish::lit!("hello{:(fg=red)} {:(italic)}", "!", 42),
+/// );
+/// ```
+///
+/// ```rust,compile_fail
+/// let name = "Ferris";
+/// stylish::lit!("{:(fg=red)}", name);
+/// ```
+#[macro_export]
+macro_rules! lit {
+    ($fmt:literal $(, $($arg:tt)*)?) => {
+        $crate::𓀄::lit!(crate=$crate, $fmt $(, $($arg)*)?)
+    };
+}
@@ -0,0 +1,285 @@
+use std::{
+    collections::BTreeMap,
+    fs::Metadata,
+    path::{is_separator, Path},
+    str::FromStr,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use stylish_style::parse_sgr;
+
+use crate::{Display, Formatter, ParseStyleError, Result, StyleDiff};
+
+/// The colors GNU `ls` uses when `LS_COLORS` does not override them.
+const DEFAULTS: &str = "di=01;34:ln=01;36:pi=33:so=01;35:do=01;35:bd=01;33:cd=01;33:ex=01;32:\
+                        su=37;41:sg=30;43:st=37;44:ow=34;42:tw=30;42";
+
+/// Keys that configure the escape codes `ls` writes rather than file styles.
+const ESCAPES: [&str; 4] = ["lc", "rc", "ec", "cl"];
+
+/// A database of file styles in the format of the `LS_COLORS` environment
+/// variable (as produced by `dircolors`), used to style paths the same way as
+/// `ls` does.
+///
+/// Entries are `:` separated `key=sgr` pairs, where `sgr` is parsed with
+/// [`StyleDiff::from_sgr`]. The key is either a two letter file type
+/// indicator (such as `di` for directories or `ex` for executables), or `*`
+/// followed by a file name suffix such as `*.tar`. Parsing starts from the
+/// same defaults as GNU `ls`, which the entries override.
+///
+/// ```rust
+/// use std::path::Path;
+///
+/// use stylish::LsColors;
+///
+/// let colors: LsColors = "di=01;34:*.toml=33".parse()?;
+/// assert_eq!(colors.style(Path::new("src")), "bold, fg=blue".parse()?);
+/// assert_eq!(colors.style(Path::new("Cargo.toml")), "fg=yellow".parse()?);
+///
+/// assert_eq!(
+///     stylish::html::format!("{:s}", colors.path(Path::new("src/lib.rs"))),
+///     "<span style=color:blue;font-weight:bolder>src</span>/lib.rs",
+/// );
+/// # Ok::<(), stylish::ParseStyleError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct LsColors {
+    indicators: BTreeMap<String, StyleDiff>,
+    link_target: bool,
+    suffixes: Vec<(String, StyleDiff)>,
+}
+
+impl LsColors {
+    /// Parse the `LS_COLORS` environment variable, returning the defaults if
+    /// it is not set.
+    pub fn from_env() -> core::result::Result<Self, ParseStyleError> {
+        std::env::var("LS_COLORS").map_or_else(|_| Ok(Self::default()), |s| s.parse())
+    }
+
+    fn extend(&mut self, s: &str) -> core::result::Result<(), ParseStyleError> {
+        let mut offset = 0;
+        for entry in s.split(':') {
+            let start = offset;
+            offset += entry.len() + 1;
+
+            // Entries without a value are not meaningful, `ls` rejects them
+            // but it's friendlier to ignore them
+            let Some((key, value)) = entry.split_once('=') else {
+                continue;
+            };
+
+            if ESCAPES.contains(&key) {
+                continue;
+            }
+            if key == "ln" && value == "target" {
+                self.link_target = true;
+                continue;
+            }
+
+            let diff = parse_sgr(start + key.len() + 1, value)?;
+            match key.strip_prefix('*') {
+                Some(suffix) => self.suffixes.push((suffix.to_string(), diff)),
+                None => {
+                    if key == "ln" {
+                        self.link_target = false;
+                    }
+                    self.indicators.insert(key.to_string(), diff);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn indicator(&self, key: &str) -> Option<StyleDiff> {
+        self.indicators.get(key).copied()
+    }
+
+    /// The style of the last matching suffix rule for `path`'s file name,
+    /// ignoring ASCII case.
+    fn suffix(&self, path: &Path) -> Option<StyleDiff> {
+        let name = path.file_name()?.to_string_lossy();
+        self.suffixes
+            .iter()
+            .rev()
+            .find(|(suffix, _)| {
+                name.len() >= suffix.len()
+                    && name.as_bytes()[name.len() - suffix.len()..]
+                        .eq_ignore_ascii_case(suffix.as_bytes())
+            })
+            .map(|&(_, diff)| diff)
+    }
+
+    /// The style for a file with the given (non-symlink) metadata.
+    fn kind(&self, path: &Path, metadata: &Metadata) -> Option<StyleDiff> {
+        let file_type = metadata.file_type();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{FileTypeExt, MetadataExt};
+
+            let first = |keys: &[&str]| keys.iter().find_map(|key| self.indicator(key));
+
+            let mode = metadata.mode();
+            let (sticky, other_writable) = (mode & 0o1000 != 0, mode & 0o002 != 0);
+            if file_type.is_dir() {
+                let special = match (sticky, other_writable) {
+                    (true, true) => first(&["tw", "ow", "st"]),
+                    (false, true) => first(&["ow"]),
+                    (true, false) => first(&["st"]),
+                    (false, false) => None,
+                };
+                return special.or_else(|| self.indicator("di"));
+            }
+            if file_type.is_fifo() {
+                return self.indicator("pi");
+            }
+            if file_type.is_socket() {
+                return self.indicator("so");
+            }
+            if file_type.is_block_device() {
+                return self.indicator("bd");
+            }
+            if file_type.is_char_device() {
+                return self.indicator("cd");
+            }
+
+            let special = [
+                (mode & 0o4000 != 0, "su"),
+                (mode & 0o2000 != 0, "sg"),
+                (mode & 0o111 != 0, "ex"),
+                (metadata.nlink() > 1, "mh"),
+            ]
+            .into_iter()
+            .filter(|&(set, _)| set)
+            .find_map(|(_, key)| self.indicator(key));
+            if let Some(special) = special {
+                return Some(special);
+            }
+        }
+
+        if file_type.is_dir() {
+            return self.indicator("di");
+        }
+        self.suffix(path).or_else(|| self.indicator("fi"))
+    }
+
+    /// The style `ls` would use for `path`, based on its file type and
+    /// permissions from the filesystem, and its file name.
+    ///
+    /// Symlinks use `ln` (or the style of their target if set to `target`),
+    /// or `or` if they are broken. Paths that don't exist use `mi`. The `no`
+    /// style applies to all paths, with the more specific style on top.
+    pub fn style(&self, path: &Path) -> StyleDiff {
+        let style = match path.symlink_metadata() {
+            Err(_) => self.indicator("mi"),
+            Ok(metadata) if metadata.file_type().is_symlink() => match path.metadata() {
+                Ok(target) if self.link_target => self.kind(path, &target),
+                Ok(_) => self.indicator("ln"),
+                Err(_) => self.indicator("or").or_else(|| self.indicator("ln")),
+            },
+            Ok(metadata) => self.kind(path, &metadata),
+        };
+        let normal = self.indicator("no").unwrap_or_default();
+        normal.merge(style.unwrap_or_default())
+    }
+
+    /// Wrap `path` in a [`Display`] implementation that styles each of its
+    /// components using [`LsColors::style`].
+    pub fn path<'a>(&'a self, path: &'a Path) -> StyledPath<'a> {
+        StyledPath {
+            colors: self,
+            path,
+            file_name_only: false,
+        }
+    }
+}
+
+impl Default for LsColors {
+    fn default() -> Self {
+        let mut colors = Self {
+            indicators: BTreeMap::new(),
+            link_target: false,
+            suffixes: Vec::new(),
+        };
+        colors
+            .extend(DEFAULTS)
+            .expect("the default colors are valid");
+        colors
+    }
+}
+
+impl FromStr for LsColors {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        let mut colors = Self::default();
+        colors.extend(s)?;
+        Ok(colors)
+    }
+}
+
+/// A [`Display`] implementation for a path that styles each component like
+/// `ls`, created by [`LsColors::path`].
+///
+/// Each component is styled based on the path up to and including it, so
+/// parent directories get the directory style. Separators are not styled.
+#[derive(Clone, Copy, Debug)]
+pub struct StyledPath<'a> {
+    colors: &'a LsColors,
+    path: &'a Path,
+    file_name_only: bool,
+}
+
+impl StyledPath<'_> {
+    /// Whether to only style the final component of the path, leaving the
+    /// parent directories unstyled.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    ///
+    /// let colors: stylish::LsColors = "*.rs=32".parse()?;
+    /// let path = colors.path(Path::new("src/lib.rs")).file_name_only(true);
+    /// assert_eq!(
+    ///     stylish::html::format!("{:s}", path),
+    ///     "src/<span style=color:green>lib.rs</span>",
+    /// );
+    /// # Ok::<(), stylish::ParseStyleError>(())
+    /// ```
+    pub fn file_name_only(self, file_name_only: bool) -> Self {
+        Self {
+            file_name_only,
+            ..self
+        }
+    }
+}
+
+impl Display for StyledPath<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let path = self.path.to_string_lossy();
+        let file_name_end = path.trim_end_matches(is_separator).len();
+
+        let mut start = 0;
+        let separators = path
+            .char_indices()
+            .filter(|&(_, c)| is_separator(c))
+            .chain([(path.len(), '/')]);
+        for (end, separator) in separators {
+            let component = &path[start..end];
+            if !component.is_empty() {
+                if self.file_name_only && end != file_name_end {
+                    f.write_str(component)?;
+                } else {
+                    let style = self.colors.style(Path::new(&path[..end]));
+                    f.with(style).write_str(component)?;
+                }
+            }
+            if end < path.len() {
+                f.write_str(&path[end..end + separator.len_utf8()])?;
+            }
+            start = end + separator.len_utf8();
+        }
+
+        Ok(())
+    }
+}
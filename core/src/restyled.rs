@@ -0,0 +1,61 @@
+use crate::{Arguments, Restyle, Result, Style, Write};
+
+/// An adaptor for a [`Write`] implementor that applies a [`Restyle`] to every
+/// style written through it, such as remapping colors for accessibility
+/// without changing any [`Display`](crate::Display) implementations.
+///
+/// When combined with [`Themed`](crate::Themed) this must be the inner writer
+/// so that it sees the styles after classes are resolved.
+///
+/// ```rust
+/// use stylish::{Palette, Restyled};
+///
+/// let mut writer = Restyled::new(stylish::ansi(String::new()), Palette::OKABE_ITO);
+/// stylish::write!(writer, "{:(fg=red)} {:(fg=green)}", "failed", "passed")?;
+/// assert_eq!(
+///     writer.into_inner().finish()?,
+///     "\x1b[38;2;213;94;0mfailed\x1b[0m \x1b[38;2;0;158;115mpassed\x1b[0m",
+/// );
+/// # Ok::<(), core::fmt::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Restyled<W, R> {
+    inner: W,
+    restyle: R,
+}
+
+impl<W: Write, R: Restyle> Restyled<W, R> {
+    /// Wrap the given output stream in this adaptor, applying `restyle` to
+    /// every style written.
+    pub fn new(inner: W, restyle: R) -> Self {
+        Self { inner, restyle }
+    }
+
+    /// Inherent delegation to [`Write::write_fmt`] to not require a trait
+    /// import.
+    pub fn write_fmt(&mut self, args: Arguments<'_>) -> Result {
+        Write::write_fmt(self, args)
+    }
+
+    /// Get back the wrapped output stream.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, R: Restyle> Write for Restyled<W, R> {
+    #[inline]
+    fn write_str(&mut self, s: &str, style: Style) -> Result {
+        self.inner.write_str(s, self.restyle.apply(style))
+    }
+
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result {
+        self.inner.start_link(url)
+    }
+
+    #[inline]
+    fn end_link(&mut self, url: &str) -> Result {
+        self.inner.end_link(url)
+    }
+}
@@ -58,15 +58,15 @@ macro_rules! std_write {
     // For most traits we can pipe them through `Display` since they have the same function
     // signature
     (@str $f:ident $val:ident [Other $($flag:ident)*]) => {
-        std_write!(@str $f $val [$($flag)*] { { debug_hex: _, } => (("",),); })
+        std_write!(@str $f $val [$($flag)*] { { fill: _, debug_hex: _, } => (("",),); })
     };
 
     // But `Debug` is special as it has extra hex flags
     (@str $f:ident $val:ident [Debug $($flag:ident)*]) => {
         std_write!(@str $f $val [$($flag)*] {
-            { debug_hex: None, } => (("?",),);
-            { debug_hex: Some(crate::formatter::DebugHex::Lower), } => (("x?",),);
-            { debug_hex: Some(crate::formatter::DebugHex::Upper), } => (("X?",),);
+            { fill: _, debug_hex: None, } => (("?",),);
+            { fill: _, debug_hex: Some(crate::formatter::DebugHex::Lower), } => (("x?",),);
+            { fill: _, debug_hex: Some(crate::formatter::DebugHex::Upper), } => (("X?",),);
         })
     };
 
@@ -1,3 +1,5 @@
+use core::ops::Range;
+
 use crate::{Display, Formatter, Result, Style, Write};
 
 /// An attributed version of [`alloc::string::String`] which has a specific
@@ -39,10 +41,33 @@ use crate::{Display, Formatter, Result, Style, Write};
 /// );
 /// # Ok::<(), std::fmt::Error>(())
 /// ```
+///
+/// With the `serde` feature enabled this is serialized as a list of spans of
+/// text, each with its [`Style`] and an optional link target.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// let s = stylish::format!("Hello {:(fg=green)}!", "World");
+///
+/// let json = serde_json::to_string(&s)?;
+/// assert_eq!(
+///     json,
+///     r#"[{"text":"Hello "},{"text":"World","style":{"foreground":"green"}},{"text":"!"}]"#,
+/// );
+///
+/// let s: stylish::String = serde_json::from_str(&json)?;
+/// assert_eq!(
+///     stylish::html::format!("{:s}", s),
+///     "Hello <span style=color:green>World</span>!",
+/// );
+/// # }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 #[derive(Default, Debug, Clone)]
 pub struct String {
     string: alloc::string::String,
     styles: alloc::vec::Vec<(usize, Style)>,
+    links: alloc::vec::Vec<(Range<usize>, alloc::string::String)>,
 }
 
 impl String {
@@ -55,6 +80,50 @@ impl String {
     pub fn new() -> Self {
         Self::default()
     }
+
+    fn write_range(&self, f: &mut Formatter<'_>, range: Range<usize>) -> Result {
+        let mut styles = self.styles.iter().peekable();
+        while let Some(&(start, style)) = styles.next() {
+            let end = styles
+                .peek()
+                .map(|&&(end, _)| end)
+                .unwrap_or_else(|| self.string.len());
+            let (start, end) = (start.max(range.start), end.min(range.end));
+            if start < end {
+                f.with(style).write_str(&self.string[start..end])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// The runs of text sharing the same style and link target, in order.
+    #[cfg(feature = "serde")]
+    fn spans(&self) -> impl Iterator<Item = (&str, Style, Option<&str>)> + '_ {
+        let mut boundaries: alloc::vec::Vec<usize> = self
+            .styles
+            .iter()
+            .map(|&(start, _)| start)
+            .chain(
+                self.links
+                    .iter()
+                    .flat_map(|(range, _)| [range.start, range.end]),
+            )
+            .chain([self.string.len()])
+            .collect();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        (1..boundaries.len()).map(move |i| {
+            let (start, end) = (boundaries[i - 1], boundaries[i]);
+            let style = self.styles[self.styles.partition_point(|&(s, _)| s <= start) - 1].1;
+            let link = self
+                .links
+                .iter()
+                .find(|(range, _)| range.contains(&start))
+                .map(|(_, url)| url.as_str());
+            (&self.string[start..end], style, link)
+        })
+    }
 }
 
 impl Write for String {
@@ -66,19 +135,91 @@ impl Write for String {
         self.string.push_str(s);
         Ok(())
     }
+
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result {
+        let start = self.string.len();
+        self.links.push((start..start, url.into()));
+        Ok(())
+    }
+
+    #[inline]
+    fn end_link(&mut self, _url: &str) -> Result {
+        if let Some((range, _)) = self.links.last_mut() {
+            range.end = self.string.len();
+        }
+        Ok(())
+    }
 }
 
 impl Display for String {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        let mut styles = self.styles.iter().peekable();
-        while let Some(&(start, style)) = styles.next() {
-            let end = styles
-                .peek()
-                .map(|&&(end, _)| end)
-                .unwrap_or_else(|| self.string.len());
-            f.with(style).write_str(&self.string[start..end])?;
+        let mut position = 0;
+        for (range, url) in &self.links {
+            self.write_range(f, position..range.start)?;
+            f.link(url, |f| self.write_range(f, range.clone()))?;
+            position = range.end;
         }
-        Ok(())
+        self.write_range(f, position..self.string.len())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Span<T> {
+    text: T,
+    #[serde(default, skip_serializing_if = "is_default")]
+    style: Style,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    link: Option<T>,
+}
+
+#[cfg(feature = "serde")]
+fn is_default(style: &Style) -> bool {
+    *style == Style::default()
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for String {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.spans()
+                .map(|(text, style, link)| Span { text, style, link }),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for String {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        use serde::de::Error as _;
+
+        let spans = alloc::vec::Vec::<Span<alloc::string::String>>::deserialize(deserializer)?;
+        let mut string = String::new();
+        let mut link = None;
+        for span in spans {
+            if span.link != link {
+                if let Some(url) = &link {
+                    string.end_link(url).map_err(D::Error::custom)?;
+                }
+                if let Some(url) = &span.link {
+                    string.start_link(url).map_err(D::Error::custom)?;
+                }
+                link = span.link;
+            }
+            string
+                .write_str(&span.text, span.style)
+                .map_err(D::Error::custom)?;
+        }
+        if let Some(url) = &link {
+            string.end_link(url).map_err(D::Error::custom)?;
+        }
+        Ok(string)
     }
 }
@@ -0,0 +1,51 @@
+use crate::{Display, Formatter, Result, StyleDiff};
+
+/// A string made up of styled spans, normally built at compile time with
+/// [`stylish::lit!`] so that writing it requires no formatting.
+///
+/// Each span's style changes are applied on top of the current style in the
+/// same way as the attributes in a format string, so attributes that a span
+/// doesn't change (such as its background color) are inherited, while those it
+/// sets explicitly (including back to their defaults) override the current
+/// style.
+///
+/// ```rust
+/// use stylish::{Color, Intensity, StyleDiff, StyledStr};
+///
+/// const ERROR: StyledStr<'static> = StyledStr::new(&[
+///     ("error", StyleDiff::new().foreground(Color::Red).intensity(Intensity::Bold)),
+///     (": ", StyleDiff::new().intensity(Intensity::Normal)),
+/// ]);
+///
+/// assert_eq!(
+///     stylish::html::format!("{:(bold)s}failed", ERROR),
+///     stylish::html::format!("{:(fg=red,bold)}{:(normal)}failed", "error", ": "),
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct StyledStr<'a> {
+    spans: &'a [(&'a str, StyleDiff)],
+}
+
+impl<'a> StyledStr<'a> {
+    /// Create a styled string from its spans of text and their style changes.
+    #[inline]
+    pub const fn new(spans: &'a [(&'a str, StyleDiff)]) -> Self {
+        Self { spans }
+    }
+
+    /// The spans of text and their style changes making up this string.
+    #[inline]
+    pub const fn spans(self) -> &'a [(&'a str, StyleDiff)] {
+        self.spans
+    }
+}
+
+impl Display for StyledStr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for &(text, diff) in self.spans {
+            f.with(diff).write_str(text)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{Arguments, Formatter, Result, Style, StyleDiff, Write};
+
+/// A mapping from class names to the style changes they make, used to resolve
+/// classes given in format strings such as `{:(class=error)}` or `{:(.path)}`.
+///
+/// Classes are looked up when the output is rendered, using the theme carried
+/// by the [`Formatter`], so the same format strings can be rendered with
+/// different themes (e.g. light, dark or no-color). Classes the theme does not
+/// know about are ignored.
+///
+/// ```rust
+/// use stylish::{Color, StyleDiff, Themed};
+///
+/// let mut error = StyleDiff::default();
+/// error.foreground = Some(Color::Red);
+/// let theme = [("error", error)];
+///
+/// let mut writer = Themed::new(stylish::html(String::new()), theme);
+/// stylish::write!(writer, "{:(.error)}: {:(class=unknown)}", "error", "oops")?;
+/// assert_eq!(
+///     writer.into_inner().finish()?,
+///     "<span style=color:red>error</span>: oops",
+/// );
+/// # Ok::<(), core::fmt::Error>(())
+/// ```
+pub trait Theme {
+    /// Get the style changes for `class`, or [`None`] if this theme does not
+    /// style it.
+    fn get(&self, class: &str) -> Option<StyleDiff>;
+}
+
+impl Theme for () {
+    #[inline]
+    fn get(&self, _class: &str) -> Option<StyleDiff> {
+        None
+    }
+}
+
+impl<T: Theme + ?Sized> Theme for &T {
+    #[inline]
+    fn get(&self, class: &str) -> Option<StyleDiff> {
+        (**self).get(class)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Theme + ?Sized> Theme for alloc::boxed::Box<T> {
+    #[inline]
+    fn get(&self, class: &str) -> Option<StyleDiff> {
+        (**self).get(class)
+    }
+}
+
+impl<S: AsRef<str>> Theme for [(S, StyleDiff)] {
+    fn get(&self, class: &str) -> Option<StyleDiff> {
+        self.iter()
+            .find(|(name, _)| name.as_ref() == class)
+            .map(|&(_, diff)| diff)
+    }
+}
+
+impl<S: AsRef<str>, const N: usize> Theme for [(S, StyleDiff); N] {
+    #[inline]
+    fn get(&self, class: &str) -> Option<StyleDiff> {
+        Theme::get(&self[..], class)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S: AsRef<str>> Theme for alloc::vec::Vec<(S, StyleDiff)> {
+    #[inline]
+    fn get(&self, class: &str) -> Option<StyleDiff> {
+        Theme::get(&self[..], class)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: core::borrow::Borrow<str> + Ord> Theme for alloc::collections::BTreeMap<K, StyleDiff> {
+    #[inline]
+    fn get(&self, class: &str) -> Option<StyleDiff> {
+        Self::get(self, class).copied()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, H> Theme for std::collections::HashMap<K, StyleDiff, H>
+where
+    K: core::borrow::Borrow<str> + core::hash::Hash + Eq,
+    H: core::hash::BuildHasher,
+{
+    #[inline]
+    fn get(&self, class: &str) -> Option<StyleDiff> {
+        Self::get(self, class).copied()
+    }
+}
+
+/// An adaptor for a [`Write`] implementor that renders data written to it with
+/// a [`Theme`], resolving any classes used in the format strings.
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+///
+/// use stylish::Themed;
+///
+/// let mut theme = BTreeMap::new();
+/// theme.insert("path", "fg=cyan,underline".parse()?);
+///
+/// let mut writer = Themed::new(stylish::ansi(String::new()), theme);
+/// stylish::write!(writer, "reading {:(.path, bold)}", "Cargo.toml")?;
+/// assert_eq!(
+///     writer.into_inner().finish()?,
+///     "reading \x1b[36;1;4mCargo.toml\x1b[0m",
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Themed<W, T> {
+    inner: W,
+    theme: T,
+}
+
+impl<W: Write, T: Theme> Themed<W, T> {
+    /// Wrap the given output stream in this adaptor, rendering with `theme`.
+    pub fn new(inner: W, theme: T) -> Self {
+        Self { inner, theme }
+    }
+
+    /// Inherent delegation to [`Write::write_fmt`] to not require a trait
+    /// import.
+    pub fn write_fmt(&mut self, args: Arguments<'_>) -> Result {
+        Write::write_fmt(self, args)
+    }
+
+    /// Get back the wrapped output stream.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write, T: Theme> Write for Themed<W, T> {
+    #[inline]
+    fn write_str(&mut self, s: &str, style: Style) -> Result {
+        self.inner.write_str(s, style)
+    }
+
+    #[inline]
+    fn write_fmt(&mut self, args: Arguments<'_>) -> Result {
+        Formatter::new(&mut self.inner)
+            .with_theme(&self.theme)
+            .write_fmt(args)
+    }
+
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result {
+        self.inner.start_link(url)
+    }
+
+    #[inline]
+    fn end_link(&mut self, url: &str) -> Result {
+        self.inner.end_link(url)
+    }
+}
@@ -90,6 +90,46 @@ pub trait Write {
     fn write_fmt(mut self: &mut Self, args: Arguments<'_>) -> Result {
         Formatter::new(&mut self).write_fmt(args)
     }
+
+    /// Marks the start of a hyperlink to `url`, all data written until the
+    /// matching [`end_link`](Write::end_link) call is the content of the link.
+    ///
+    /// This method should generally not be invoked manually, but rather through
+    /// [`Formatter::link`] which ensures links are correctly ended and not
+    /// nested. The default implementation ignores the link and only the
+    /// content is written.
+    ///
+    /// ```rust
+    /// use stylish::{Style, Write};
+    ///
+    /// let mut s = String::new();
+    /// {
+    ///     let mut output = stylish::html(&mut s);
+    ///     output.write_str("see ", Style::default())?;
+    ///     output.start_link("https://example.com")?;
+    ///     output.write_str("docs", Style::default())?;
+    ///     output.end_link("https://example.com")?;
+    ///     output.finish()?;
+    /// }
+    ///
+    /// assert_eq!(s, r#"see <a href="https://example.com">docs</a>"#);
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    #[inline]
+    fn start_link(&mut self, url: &str) -> Result {
+        let _ = url;
+        Ok(())
+    }
+
+    /// Marks the end of a hyperlink started by [`start_link`](Write::start_link),
+    /// `url` is the same as was passed to that call.
+    ///
+    /// The default implementation does nothing.
+    #[inline]
+    fn end_link(&mut self, url: &str) -> Result {
+        let _ = url;
+        Ok(())
+    }
 }
 
 impl<W: Write + ?Sized> Write for &mut W {
@@ -104,6 +144,14 @@ impl<W: Write + ?Sized> Write for &mut W {
     fn write_fmt(&mut self, args: Arguments<'_>) -> Result {
         (**self).write_fmt(args)
     }
+
+    fn start_link(&mut self, url: &str) -> Result {
+        (**self).start_link(url)
+    }
+
+    fn end_link(&mut self, url: &str) -> Result {
+        (**self).end_link(url)
+    }
 }
 
 /// Writes attributed and formatted data into a buffer.
@@ -1,7 +1,7 @@
 use core::fmt;
 
 use askama_escape::{escape, Html as AskamaHtml};
-use stylish_core::{Style, Write};
+use stylish_core::{Palette, Style, Write};
 
 use crate::util;
 
@@ -17,10 +17,14 @@ use crate::util;
 /// );
 /// # Ok::<(), core::fmt::Error>(())
 /// ```
+///
+/// Hyperlinks are written as `<a>` elements wrapping the styled content of the
+/// link.
 #[derive(Clone, Debug, Default)]
 pub struct Html<T: core::fmt::Write> {
     inner: T,
     current: Style,
+    palette: Option<Palette>,
 }
 
 impl<T: core::fmt::Write> Html<T> {
@@ -29,6 +33,30 @@ impl<T: core::fmt::Write> Html<T> {
         Self {
             inner,
             current: Style::default(),
+            palette: None,
+        }
+    }
+
+    /// Render named colors (and the first 16 entries of the 256-color palette)
+    /// as exact RGB values from `palette`, instead of the default CSS named
+    /// colors.
+    ///
+    /// ```rust
+    /// let mut palette = stylish::Palette::XTERM;
+    /// palette.colors[1] = (0xab, 0x46, 0x42);
+    ///
+    /// let mut writer = stylish::Html::new(String::new()).palette(palette);
+    /// stylish::write!(writer, "Hello {:(fg=red)}", "Ferris")?;
+    /// assert_eq!(
+    ///     writer.finish()?,
+    ///     "Hello <span style=color:#ab4642>Ferris</span>",
+    /// );
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    pub fn palette(self, palette: Palette) -> Self {
+        Self {
+            palette: Some(palette),
+            ..self
         }
     }
 
@@ -43,10 +71,17 @@ impl<T: core::fmt::Write> Html<T> {
     /// it, if you don't call this the stream will be left in whatever style
     /// the last output data was.
     pub fn finish(mut self) -> Result<T, fmt::Error> {
+        self.close()?;
+        Ok(self.inner)
+    }
+
+    /// Close the currently open `<span>`, if any.
+    fn close(&mut self) -> fmt::Result {
         if self.current != Style::default() {
             self.inner.write_str("</span>")?;
+            self.current = Style::default();
         }
-        Ok(self.inner)
+        Ok(())
     }
 }
 
@@ -56,27 +91,24 @@ impl<T: fmt::Write> Write for Html<T> {
             return Ok(());
         }
 
+        let style = style.inherit_from(Style::default());
+
         if style == Style::default() {
             if self.current != Style::default() {
                 self.inner.write_str("</span>")?;
             }
         } else if style != self.current {
             let diff = style.diff_from(Style::default());
-            let segments = [
-                diff.foreground.map(util::foreground),
-                diff.background.map(util::background),
-                diff.intensity.map(util::intensity),
-            ];
-            let mut segments = segments.iter().filter_map(|&s| s);
+            let mut segments = util::segments(diff, self.palette.as_ref())
+                .into_iter()
+                .flatten();
             if let Some(segment) = segments.next() {
                 if self.current != Style::default() {
                     self.inner.write_str("</span>")?;
                 }
-                self.inner.write_str("<span style=")?;
-                self.inner.write_str(segment)?;
+                write!(self.inner, "<span style={segment}")?;
                 for segment in segments {
-                    self.inner.write_str(";")?;
-                    self.inner.write_str(segment)?;
+                    write!(self.inner, ";{segment}")?;
                 }
                 self.inner.write_str(">")?;
             }
@@ -87,4 +119,14 @@ impl<T: fmt::Write> Write for Html<T> {
 
         Ok(())
     }
+
+    fn start_link(&mut self, url: &str) -> fmt::Result {
+        self.close()?;
+        write!(self.inner, "<a href=\"{}\">", util::Url(url))
+    }
+
+    fn end_link(&mut self, _url: &str) -> fmt::Result {
+        self.close()?;
+        self.inner.write_str("</a>")
+    }
 }
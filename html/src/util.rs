@@ -1,7 +1,92 @@
-use stylish_core::{Color, Intensity};
+use core::fmt;
 
-pub(crate) fn foreground(color: Color) -> &'static str {
-    match color {
+use stylish_core::{Color, Intensity, Palette, StyleDiff, Underline};
+
+/// A single CSS declaration, some of which must be computed at runtime.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Segment {
+    Static(&'static str),
+    Rgb(&'static str, (u8, u8, u8)),
+    TextDecoration {
+        underline: bool,
+        overline: bool,
+        line_through: bool,
+        blink: bool,
+    },
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Segment::Static(segment) => f.write_str(segment),
+            Segment::Rgb(property, (r, g, b)) => write!(f, "{property}:#{r:02x}{g:02x}{b:02x}"),
+            Segment::TextDecoration {
+                underline,
+                overline,
+                line_through,
+                blink,
+            } => {
+                let lines = [
+                    (underline, "underline"),
+                    (overline, "overline"),
+                    (line_through, "line-through"),
+                    (blink, "blink"),
+                ];
+                let mut lines = lines.iter().filter(|(set, _)| *set).map(|(_, line)| line);
+                f.write_str("text-decoration-line:")?;
+                if let Some(line) = lines.next() {
+                    f.write_str(line)?;
+                }
+                for line in lines {
+                    write!(f, " {line}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A URL escaped for use in a double-quoted attribute, with whitespace and
+/// control characters percent-encoded.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Url<'a>(pub(crate) &'a str);
+
+impl fmt::Display for Url<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&#x27;")?,
+                c if c.is_whitespace() || c.is_control() => {
+                    for byte in c.encode_utf8(&mut [0; 4]).bytes() {
+                        write!(f, "%{byte:02X}")?;
+                    }
+                }
+                c => fmt::Write::write_char(f, c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The exact RGB value to render `color` as, named colors are only resolved
+/// when a palette is given so that they use the browser's defaults otherwise.
+fn rgb(color: Color, palette: Option<&Palette>) -> Option<(u8, u8, u8)> {
+    match (color, palette) {
+        (_, Some(palette)) => palette.rgb(color),
+        (Color::Ansi256(_) | Color::Rgb(..), None) => Palette::XTERM.rgb(color),
+        _ => None,
+    }
+}
+
+pub(crate) fn foreground(color: Color, palette: Option<&Palette>) -> Segment {
+    if let Some(rgb) = rgb(color, palette) {
+        return Segment::Rgb("color", rgb);
+    }
+    Segment::Static(match color {
         Color::Black => "color:black",
         Color::Red => "color:red",
         Color::Green => "color:green",
@@ -22,11 +107,14 @@ pub(crate) fn foreground(color: Color) -> &'static str {
         Color::BrightWhite => "color:#ffffff",
 
         _ => "color:inherit",
-    }
+    })
 }
 
-pub(crate) fn background(color: Color) -> &'static str {
-    match color {
+pub(crate) fn background(color: Color, palette: Option<&Palette>) -> Segment {
+    if let Some(rgb) = rgb(color, palette) {
+        return Segment::Rgb("background-color", rgb);
+    }
+    Segment::Static(match color {
         Color::Black => "background-color:black",
         Color::Red => "background-color:red",
         Color::Green => "background-color:green",
@@ -47,14 +135,87 @@ pub(crate) fn background(color: Color) -> &'static str {
         Color::BrightWhite => "background-color:#ffffff",
 
         _ => "background-color:inherit",
+    })
+}
+
+pub(crate) fn underline_color(color: Color, palette: Option<&Palette>) -> Segment {
+    if let Some(rgb) = rgb(color, palette) {
+        return Segment::Rgb("text-decoration-color", rgb);
     }
+    Segment::Static(match color {
+        Color::Black => "text-decoration-color:black",
+        Color::Red => "text-decoration-color:red",
+        Color::Green => "text-decoration-color:green",
+        Color::Yellow => "text-decoration-color:yellow",
+        Color::Blue => "text-decoration-color:blue",
+        Color::Magenta => "text-decoration-color:magenta",
+        Color::Cyan => "text-decoration-color:cyan",
+        Color::White => "text-decoration-color:white",
+        Color::Default => "text-decoration-color:currentcolor",
+
+        Color::BrightBlack => "text-decoration-color:gray",
+        Color::BrightRed => "text-decoration-color:#af0000",
+        Color::BrightGreen => "text-decoration-color:#00ff00",
+        Color::BrightYellow => "text-decoration-color:#ffff00",
+        Color::BrightBlue => "text-decoration-color:#0000ff",
+        Color::BrightMagenta => "text-decoration-color:#ff00ff",
+        Color::BrightCyan => "text-decoration-color:#00ffff",
+        Color::BrightWhite => "text-decoration-color:#ffffff",
+
+        _ => "text-decoration-color:currentcolor",
+    })
+}
+
+pub(crate) fn underline_style(underline: Underline) -> Option<Segment> {
+    Some(Segment::Static(match underline {
+        Underline::Double => "text-decoration-style:double",
+        Underline::Curly => "text-decoration-style:wavy",
+        Underline::Dotted => "text-decoration-style:dotted",
+        Underline::Dashed => "text-decoration-style:dashed",
+        _ => return None,
+    }))
 }
 
-pub(crate) fn intensity(intensity: Intensity) -> &'static str {
-    match intensity {
+pub(crate) fn intensity(intensity: Intensity) -> Segment {
+    Segment::Static(match intensity {
         Intensity::Bold => "font-weight:bolder",
         Intensity::Faint => "font-weight:lighter",
         Intensity::Normal => "font-weight:inherit",
         _ => "font-weight:inherit",
-    }
+    })
+}
+
+fn text_decoration(diff: StyleDiff) -> Option<Segment> {
+    let underline = diff.underline.is_some_and(|u| u != Underline::None);
+    let overline = diff.overline.unwrap_or(false);
+    let line_through = diff.strikethrough.unwrap_or(false);
+    let blink = diff.blink.unwrap_or(false);
+    (underline || overline || line_through || blink).then_some(Segment::TextDecoration {
+        underline,
+        overline,
+        line_through,
+        blink,
+    })
+}
+
+fn flag(declaration: &'static str) -> impl Fn(bool) -> Option<Segment> {
+    move |value| value.then_some(Segment::Static(declaration))
+}
+
+/// All the CSS declarations required to apply `diff` to the default style, in
+/// the order they should be written, optionally resolving named colors with
+/// `palette`.
+pub(crate) fn segments(diff: StyleDiff, palette: Option<&Palette>) -> [Option<Segment>; 9] {
+    [
+        diff.foreground.map(|color| foreground(color, palette)),
+        diff.background.map(|color| background(color, palette)),
+        diff.intensity.map(intensity),
+        diff.italic.and_then(flag("font-style:italic")),
+        text_decoration(diff),
+        diff.underline.and_then(underline_style),
+        diff.underline_color
+            .map(|color| underline_color(color, palette)),
+        diff.reverse.and_then(flag("filter:invert(100%)")),
+        diff.hidden.and_then(flag("visibility:hidden")),
+    ]
 }
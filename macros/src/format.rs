@@ -1,17 +1,54 @@
-use std::str::FromStr;
+use std::{num::ParseIntError, str::FromStr};
 
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{anychar, char, digit1, none_of, satisfy},
-    combinator::{all_consuming, cut, map, map_res, opt, recognize, value},
-    multi::{many0, many1, separated_list0},
+    combinator::{all_consuming, consumed, cut, map, map_res, opt, recognize, value},
+    error::{ErrorKind, FromExternalError, ParseError},
+    multi::{many0, many1},
     sequence::{delimited, pair, preceded, terminated},
-    IResult,
 };
-use stylish_style::{Background, Color, Foreground, Intensity, Restyle, Style, StyleDiff};
+use stylish_style::{ParseStyleError, StyleDiff};
 
-fn identifier(input: &str) -> IResult<&str, &str> {
+/// A failure to parse a format string, pointing at the remaining input where
+/// parsing failed.
+#[derive(Debug, Clone)]
+pub struct Error<'a> {
+    pub input: &'a str,
+    /// The error from parsing an attribute list, whose span is relative to
+    /// `input`.
+    pub style: Option<ParseStyleError>,
+}
+
+impl<'a> ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, _: ErrorKind) -> Self {
+        Self { input, style: None }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a> FromExternalError<&'a str, ParseStyleError> for Error<'a> {
+    fn from_external_error(input: &'a str, _: ErrorKind, error: ParseStyleError) -> Self {
+        Self {
+            input,
+            style: Some(error),
+        }
+    }
+}
+
+impl<'a> FromExternalError<&'a str, ParseIntError> for Error<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: ParseIntError) -> Self {
+        Self::from_error_kind(input, kind)
+    }
+}
+
+pub type IResult<'a, T> = nom::IResult<&'a str, T, Error<'a>>;
+
+fn identifier(input: &str) -> IResult<'_, &str> {
     recognize(pair(
         alt((satisfy(unicode_ident::is_xid_start), char('_'))),
         many0(satisfy(unicode_ident::is_xid_continue)),
@@ -19,64 +56,35 @@ fn identifier(input: &str) -> IResult<&str, &str> {
 }
 
 pub trait Parse<'a>: Sized {
-    fn parse(input: &'a str) -> IResult<&'a str, Self>;
-}
-
-impl<'a> Parse<'a> for Color {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        alt((
-            value(Color::Black, tag("black")),
-            value(Color::Red, tag("red")),
-            value(Color::Green, tag("green")),
-            value(Color::Yellow, tag("yellow")),
-            value(Color::Blue, tag("blue")),
-            value(Color::Magenta, tag("magenta")),
-            value(Color::Cyan, tag("cyan")),
-            value(Color::White, tag("white")),
-            value(Color::Default, tag("default")),
-        ))(input)
-    }
+    fn parse(input: &'a str) -> IResult<'a, Self>;
 }
 
-impl<'a> Parse<'a> for Intensity {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        alt((
-            value(Intensity::Normal, tag("normal")),
-            value(Intensity::Bold, tag("bold")),
-            value(Intensity::Faint, tag("faint")),
-        ))(input)
-    }
+/// The contents of an attribute list, which may contain nested parentheses
+/// such as `fg=rgb(1, 2, 3)`.
+fn attributes(input: &str) -> IResult<'_, &str> {
+    recognize(many0(alt((
+        recognize(none_of("(){}")),
+        recognize(delimited(char('('), attributes, char(')'))),
+    ))))(input)
 }
 
-impl<'a> Parse<'a> for Box<dyn Restyle> {
-    #[allow(trivial_casts)]
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        alt((
-            map(
-                preceded(tag("fg"), cut(preceded(tag("="), Color::parse))),
-                |color| Box::new(Foreground(color)) as _,
-            ),
-            map(
-                preceded(tag("bg"), cut(preceded(tag("="), Color::parse))),
-                |color| Box::new(Background(color)) as _,
-            ),
-            map(Intensity::parse, |intensity| Box::new(intensity) as _),
-        ))(input)
-    }
+/// The style changes and theme classes given in an attribute list.
+#[derive(Debug, Default, Clone)]
+pub struct Attributes<'a> {
+    pub classes: Vec<&'a str>,
+    pub style: StyleDiff,
 }
 
-impl<'a> Parse<'a> for StyleDiff {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        // TODO: This should only allow each variant once, but there's no sort of
-        // optional-permutation helper in nom
-        map(
-            separated_list0(tag(","), cut(<Box<dyn Restyle>>::parse)),
-            |restyles| {
-                Style::default()
-                    .with(restyles.as_slice())
-                    .diff_from(Style::default())
-            },
-        )(input)
+impl<'a> Parse<'a> for Attributes<'a> {
+    fn parse(input: &'a str) -> IResult<'a, Self> {
+        map_res(attributes, |input| {
+            let mut classes = Vec::new();
+            let style = stylish_style::parse_with_classes(input, |class, _| {
+                classes.push(class);
+                Ok(())
+            })?;
+            Ok::<_, ParseStyleError>(Attributes { classes, style })
+        })(input)
     }
 }
 
@@ -88,7 +96,7 @@ pub enum Align {
 }
 
 impl<'a> Parse<'a> for Align {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<'a, Self> {
         alt((
             value(Self::Left, tag("<")),
             value(Self::Center, tag("^")),
@@ -104,7 +112,7 @@ pub enum Sign {
 }
 
 impl<'a> Parse<'a> for Sign {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<'a, Self> {
         alt((value(Self::Plus, tag("+")), value(Self::Minus, tag("-"))))(input)
     }
 }
@@ -115,8 +123,9 @@ pub enum DebugHex {
     Upper,
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct FormatterArgs<'a> {
+    pub fill: char,
     pub align: Option<Align>,
     pub sign: Option<Sign>,
     pub alternate: bool,
@@ -126,8 +135,24 @@ pub struct FormatterArgs<'a> {
     pub debug_hex: Option<DebugHex>,
 }
 
-#[derive(Debug, Clone, Copy)]
+impl Default for FormatterArgs<'_> {
+    fn default() -> Self {
+        Self {
+            fill: ' ',
+            align: None,
+            sign: None,
+            alternate: false,
+            zero: false,
+            width: None,
+            precision: None,
+            debug_hex: None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
 pub enum FormatTrait {
+    #[default]
     Display,
     Debug,
     Octal,
@@ -138,12 +163,7 @@ pub enum FormatTrait {
     LowerExp,
     UpperExp,
     Stylish,
-}
-
-impl Default for FormatTrait {
-    fn default() -> Self {
-        Self::Display
-    }
+    StylishDebug,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -153,7 +173,7 @@ pub enum Count<'a> {
 }
 
 impl<'a> Parse<'a> for Count<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<'a, Self> {
         alt((
             map(terminated(FormatArgRef::parse, tag("$")), Self::Parameter),
             map(map_res(digit1, usize::from_str), Self::Integer),
@@ -164,28 +184,42 @@ impl<'a> Parse<'a> for Count<'a> {
 #[derive(Debug, Default, Clone)]
 pub struct FormatSpec<'a> {
     pub formatter_args: FormatterArgs<'a>,
-    pub style: StyleDiff,
+    /// The attributes given before the alignment, which also apply to any
+    /// padding.
+    pub outer_attributes: Attributes<'a>,
+    pub attributes: Attributes<'a>,
     pub format_trait: FormatTrait,
 }
 
+fn fill_align(input: &str) -> IResult<'_, (char, Align)> {
+    alt((
+        pair(anychar, Align::parse),
+        map(Align::parse, |align| (' ', align)),
+    ))(input)
+}
+
+fn attribute_list<'a>(input: &'a str) -> IResult<'a, Attributes<'a>> {
+    delimited(tag("("), cut(Attributes::parse), tag(")"))(input)
+}
+
 impl<'a> Parse<'a> for FormatSpec<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        let (input, align) = opt(alt((
-            pair(anychar, Align::parse),
-            map(Align::parse, |align| (' ', align)),
-        )))(input)?;
-        let align = align.map(|(fill, align)| {
-            if fill != ' ' {
-                todo!()
-            }
-            align
-        });
+    fn parse(input: &'a str) -> IResult<'a, Self> {
+        // A `(` followed by an alignment is a fill character, otherwise it
+        // starts an attribute list that applies to the padding too
+        let (input, (outer_attributes, fill_align)) = alt((
+            map(fill_align, |fill_align| (None, Some(fill_align))),
+            pair(opt(attribute_list), opt(fill_align)),
+        ))(input)?;
+        let (fill, align) = match fill_align {
+            Some((fill, align)) => (fill, Some(align)),
+            None => (' ', None),
+        };
         let (input, sign) = opt(Sign::parse)(input)?;
         let (input, alternate) = opt(value(true, tag("#")))(input)?;
         let (input, zero) = opt(value(true, tag("0")))(input)?;
         let (input, width) = opt(Count::parse)(input)?;
         let (input, precision) = opt(preceded(tag("."), Count::parse))(input)?;
-        let (input, style) = opt(delimited(tag("("), cut(StyleDiff::parse), tag(")")))(input)?;
+        let (input, attributes) = opt(attribute_list)(input)?;
         let (input, debug_hex_and_format_trait) = opt(alt((
             value((None, FormatTrait::Debug), tag("?")),
             value((Some(DebugHex::Lower), FormatTrait::Debug), tag("x?")),
@@ -197,6 +231,7 @@ impl<'a> Parse<'a> for FormatSpec<'a> {
             value((None, FormatTrait::Binary), tag("b")),
             value((None, FormatTrait::LowerExp), tag("e")),
             value((None, FormatTrait::UpperExp), tag("E")),
+            value((None, FormatTrait::StylishDebug), tag("s?")),
             value((None, FormatTrait::Stylish), tag("s")),
         )))(input)?;
         let debug_hex = debug_hex_and_format_trait.and_then(|(debug_hex, _)| debug_hex);
@@ -205,6 +240,7 @@ impl<'a> Parse<'a> for FormatSpec<'a> {
             input,
             FormatSpec {
                 formatter_args: FormatterArgs {
+                    fill,
                     align,
                     sign,
                     alternate: alternate.unwrap_or_default(),
@@ -213,7 +249,8 @@ impl<'a> Parse<'a> for FormatSpec<'a> {
                     precision,
                     debug_hex,
                 },
-                style: style.unwrap_or_default(),
+                outer_attributes: outer_attributes.unwrap_or_default(),
+                attributes: attributes.unwrap_or_default(),
                 format_trait: format_trait.unwrap_or_default(),
             },
         ))
@@ -227,7 +264,7 @@ pub enum FormatArgRef<'a> {
 }
 
 impl<'a> Parse<'a> for FormatArgRef<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<'a, Self> {
         alt((
             map(map_res(digit1, usize::from_str), FormatArgRef::Positional),
             map(identifier, FormatArgRef::Named),
@@ -237,21 +274,25 @@ impl<'a> Parse<'a> for FormatArgRef<'a> {
 
 #[derive(Debug, Clone)]
 pub struct FormatArg<'a> {
+    /// The text of the argument between the braces, used to locate errors.
+    pub source: &'a str,
     pub arg: Option<FormatArgRef<'a>>,
     pub format_spec: FormatSpec<'a>,
 }
 
 impl<'a> Parse<'a> for FormatArg<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
-        let (input, arg) = opt(FormatArgRef::parse)(input)?;
-        let (input, format_spec) = opt(preceded(tag(":"), FormatSpec::parse))(input)?;
-        Ok((
-            input,
-            Self {
+    fn parse(input: &'a str) -> IResult<'a, Self> {
+        map(
+            consumed(pair(
+                opt(FormatArgRef::parse),
+                opt(preceded(tag(":"), FormatSpec::parse)),
+            )),
+            |(source, (arg, format_spec))| Self {
+                source,
                 arg,
                 format_spec: format_spec.unwrap_or_default(),
             },
-        ))
+        )(input)
     }
 }
 
@@ -263,7 +304,7 @@ pub enum Piece<'a> {
 }
 
 impl<'a> Piece<'a> {
-    pub fn parse_lit(input: &'a str) -> IResult<&'a str, Self> {
+    pub fn parse_lit(input: &'a str) -> IResult<'a, Self> {
         alt((
             map(recognize(many1(none_of("{}"))), Self::Lit),
             value(Self::Lit("{"), tag("{{")),
@@ -271,16 +312,16 @@ impl<'a> Piece<'a> {
         ))(input)
     }
 
-    pub fn parse_arg(input: &'a str) -> IResult<&'a str, Self> {
+    pub fn parse_arg(input: &'a str) -> IResult<'a, Self> {
         map(
-            delimited(tag("{"), cut(FormatArg::parse), tag("}")),
+            delimited(tag("{"), cut(FormatArg::parse), cut(tag("}"))),
             Self::Arg,
         )(input)
     }
 }
 
 impl<'a> Parse<'a> for Piece<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<'a, Self> {
         alt((Self::parse_lit, Self::parse_arg))(input)
     }
 }
@@ -291,7 +332,7 @@ pub struct Format<'a> {
 }
 
 impl<'a> Parse<'a> for Format<'a> {
-    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+    fn parse(input: &'a str) -> IResult<'a, Self> {
         all_consuming(map(many0(Piece::parse), |pieces| Self { pieces }))(input)
     }
 }
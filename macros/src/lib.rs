@@ -5,18 +5,26 @@
 #![allow(uncommon_codepoints)]
 #![cfg_attr(stylish_proc_macro_expand, feature(proc_macro_expand))]
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
 
-use proc_macro2::Span;
+use nom::Offset as _;
+use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
+use stylish_style::{ParseStyleError, Style, StyleDiff};
 use syn::{
     parse::{ParseStream, Result},
-    parse_macro_input, Expr, ExprAssign, ExprPath, Ident, Index, LitStr, Path, PathArguments,
-    Token,
+    parse_macro_input, Expr, ExprAssign, ExprLit, ExprPath, Ident, Index, Lit, LitStr, Path,
+    PathArguments, Token,
 };
 
 use self::{
-    format::{Format, FormatArg, FormatArgRef, FormatSpec, Parse as _, Piece},
+    format::{
+        Attributes, Count, Format, FormatArg, FormatArgRef, FormatSpec, FormatTrait, FormatterArgs,
+        Parse as _, Piece,
+    },
     to_tokens::Scoped,
 };
 
@@ -75,12 +83,21 @@ impl syn::parse::Parse for ArgsInput {
                     } else {
                         panic!()
                     };
+                    if named_args.iter().any(|(name, _)| *name == ident) {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!("duplicate argument named `{ident}`"),
+                        ));
+                    }
                     named_args.push((ident, *right));
                     onto_named = true;
                 }
                 expr => {
                     if onto_named {
-                        panic!("positional arg after named")
+                        return Err(syn::Error::new_spanned(
+                            expr,
+                            "positional arguments cannot follow named arguments",
+                        ));
                     }
                     positional_args.push(expr);
                 }
@@ -95,6 +112,137 @@ impl syn::parse::Parse for ArgsInput {
     }
 }
 
+/// The span of `range` within the format string literal, or of the whole
+/// literal when the compiler can't point within it.
+///
+/// Pointing within a literal needs the unstable `proc_macro_span` feature, so
+/// on stable compilers every error in the format string falls back to the
+/// span of the whole literal. It also falls back for literals whose source
+/// differs from their value (raw strings or those containing escapes), as the
+/// offsets into the value don't match the source.
+fn format_span(format: &LitStr, range: Range<usize>) -> Span {
+    let token = format.token();
+    let source = token.to_string();
+    let plain = source
+        .strip_prefix('"')
+        .and_then(|source| source.strip_suffix('"'))
+        == Some(&*format.value());
+    plain
+        .then(|| token.subspan(range.start + 1..range.end + 1))
+        .flatten()
+        .unwrap_or_else(|| format.span())
+}
+
+/// The message for an error parsing style attributes from `source`.
+fn style_error(err: &ParseStyleError, source: &str) -> String {
+    match &source[err.span()] {
+        "" => format!("invalid style: {}", err.kind()),
+        text => format!("invalid style: {} `{text}`", err.kind()),
+    }
+}
+
+/// The span of a placeholder, including its braces, in the format string.
+fn arg_span(format: &LitStr, source: &str, placeholder: &str) -> Span {
+    let start = source.offset(placeholder) - 1;
+    format_span(format, start..start + placeholder.len() + 2)
+}
+
+fn parse_format<'a>(format: &LitStr, source: &'a str) -> Result<Format<'a>> {
+    let error = match Format::parse(source) {
+        Ok((_, parsed)) => return Ok(parsed),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => error,
+        Err(nom::Err::Incomplete(_)) => unreachable!("only complete parsers are used"),
+    };
+    let offset = source.offset(error.input);
+    if let Some(style) = error.style {
+        let span = offset + style.span().start..offset + style.span().end;
+        return Err(syn::Error::new(
+            format_span(format, span),
+            style_error(&style, error.input),
+        ));
+    }
+    Err(match error.input.chars().next() {
+        None => syn::Error::new(
+            format.span(),
+            "invalid format string: expected `}` but string was terminated",
+        ),
+        Some(c) => {
+            let span = format_span(format, offset..offset + c.len_utf8());
+            if c == '}' {
+                syn::Error::new(span, "invalid format string: unmatched `}` found")
+            } else {
+                syn::Error::new(
+                    span,
+                    format!("invalid format string: expected `}}`, found `{c}`"),
+                )
+            }
+        }
+    })
+}
+
+/// Describes how many positional arguments were given, for errors about
+/// references to missing arguments.
+fn given_arguments(count: usize) -> String {
+    match count {
+        0 => "no arguments were given".to_owned(),
+        1 => "there is 1 argument".to_owned(),
+        count => format!("there are {count} arguments"),
+    }
+}
+
+/// The error for implicit placeholders past the end of the positional
+/// arguments, spanned to the first of them.
+fn missing_positional_args(span: Span, placeholders: usize, arguments: usize) -> syn::Error {
+    let s = if placeholders == 1 { "" } else { "s" };
+    syn::Error::new(
+        span,
+        format!(
+            "{placeholders} positional argument{s} in format string, but {}",
+            given_arguments(arguments)
+        ),
+    )
+}
+
+/// The error for explicitly indexed placeholders past the end of the
+/// positional arguments.
+fn invalid_positional_arg(span: Span, index: usize, arguments: usize) -> syn::Error {
+    syn::Error::new(
+        span,
+        format!(
+            "invalid reference to positional argument {index} ({})",
+            given_arguments(arguments)
+        ),
+    )
+}
+
+/// Combines errors for each argument that is not referenced by the format
+/// string, if any.
+fn unused_args<'a>(
+    positional: impl IntoIterator<Item = &'a Expr>,
+    named: impl IntoIterator<Item = &'a Expr>,
+) -> Result<()> {
+    positional
+        .into_iter()
+        .map(|expr| syn::Error::new_spanned(expr, "argument never used"))
+        .chain(
+            named
+                .into_iter()
+                .map(|expr| syn::Error::new_spanned(expr, "named argument never used")),
+        )
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        })
+        .map_or(Ok(()), Err)
+}
+
+/// Expands to `error` in expression position, which may combine multiple
+/// `compile_error!` invocations.
+fn compile_error(error: syn::Error) -> TokenStream {
+    let error = error.into_compile_error();
+    quote!({ #error })
+}
+
 fn format_args_impl(
     ArgsInput {
         krate,
@@ -102,18 +250,20 @@ fn format_args_impl(
         positional_args,
         named_args,
     }: ArgsInput,
-) -> impl ToTokens {
+) -> Result<TokenStream> {
     let krate = krate.expect("base crate not specified (are you using stylish-macros directly instead of through stylish-core?)");
     let export: syn::Path = syn::parse_quote!(#krate::𓀄);
 
     let span = format.span();
     let format_string = &format;
-    let format = format.value();
-    let (leftover, format) = Format::parse(&format).unwrap();
-    assert!(leftover.is_empty());
+    let source = format.value();
+    let parsed = parse_format(&format, &source)?;
     let num_positional_args = positional_args.len();
-    let positional_args = positional_args.into_iter();
-    let positional_args = quote! { (#(&#positional_args,)*) };
+    let num_implicit_placeholders = parsed
+        .pieces
+        .iter()
+        .filter(|piece| matches!(piece, Piece::Arg(FormatArg { arg: None, .. })))
+        .count();
     let (named_args_names, named_args_values): (Vec<_>, Vec<_>) = named_args.into_iter().unzip();
     let named_args_names: HashMap<String, usize> = named_args_names
         .into_iter()
@@ -121,95 +271,140 @@ fn format_args_impl(
         .enumerate()
         .map(|(i, s)| (s, i))
         .collect();
-    let named_args_values = named_args_values.into_iter();
-    let named_args_values = quote! {
-        (#(&#named_args_values,)*)
-    };
     let mut implicit_named_args_values = Vec::new();
     let mut used_positional_args = HashSet::new();
     let mut used_named_args = HashSet::new();
     let mut next_arg_iter = 0..num_positional_args;
-    let statements: Vec<_> = format
-        .pieces
-        .into_iter()
-        .map(|piece| match piece {
+    let mut resolve = |arg: Option<FormatArgRef<'_>>, span: Span| match arg {
+        None => {
+            let i = next_arg_iter.next().ok_or_else(|| {
+                missing_positional_args(span, num_implicit_placeholders, num_positional_args)
+            })?;
+            used_positional_args.insert(i);
+            let index = Index::from(i);
+            Ok(quote!(__stylish_positional_args.#index))
+        }
+        Some(FormatArgRef::Positional(i)) => {
+            if i >= num_positional_args {
+                return Err(invalid_positional_arg(span, i, num_positional_args));
+            }
+            used_positional_args.insert(i);
+            let index = Index::from(i);
+            Ok(quote!(__stylish_positional_args.#index))
+        }
+        Some(FormatArgRef::Named(name)) => {
+            if let Some(&i) = named_args_names.get(name) {
+                used_named_args.insert(i);
+                let index = Index::from(i);
+                Ok(quote!(__stylish_named_args.#index))
+            } else {
+                let i = implicit_named_args_values.len();
+                implicit_named_args_values.push(ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path: Ident::new_raw(name, Span::call_site().resolved_at(format_string.span()))
+                        .into(),
+                });
+                let index = Index::from(i);
+                Ok(quote!(__stylish_implicit_named_args.#index))
+            }
+        }
+    };
+    let mut statements = Vec::new();
+    for piece in parsed.pieces {
+        let FormatArg {
+            source: placeholder,
+            arg,
+            format_spec:
+                FormatSpec {
+                    formatter_args,
+                    outer_attributes,
+                    attributes,
+                    format_trait,
+                },
+        } = match piece {
             Piece::Lit(lit) => {
                 let lit = LitStr::new(&lit.replace("{{", "{"), span);
-                quote!(#export::Formatter::write_str(__stylish_formatter, #lit)?)
+                statements.push(quote!(#export::Formatter::write_str(__stylish_formatter, #lit)?));
+                continue;
             }
-            Piece::Arg(FormatArg {
-                arg,
-                format_spec:
-                    FormatSpec {
-                        formatter_args,
-                        style,
-                        format_trait,
-                    },
-            }) => {
-                let formatter_args = Scoped::new(&export, &formatter_args);
+            Piece::Arg(arg) => arg,
+        };
+        let placeholder_span = arg_span(&format, &source, placeholder);
+
+        let mut count = |count: Option<Count<'_>>| {
+            count
+                .map(|count| match count {
+                    Count::Integer(value) => Ok(quote!(&#value)),
+                    Count::Parameter(arg) => resolve(Some(arg), placeholder_span),
+                })
+                .transpose()
+        };
+        let width = count(formatter_args.width)?;
+        let precision = count(formatter_args.precision)?;
+        let formatter_args = (formatter_args, width, precision);
+        let formatter_args = Scoped::new(&export, &formatter_args);
+
+        let arg = resolve(arg, placeholder_span)?;
+
+        // Stylish arguments are padded by `Padded`, so only the outer
+        // attributes apply to the padding, while other traits pad their
+        // output themselves
+        let (classes, style, arg) = match format_trait {
+            FormatTrait::Stylish => {
+                let Attributes { classes, style } = attributes;
                 let style = Scoped::new(&export, &style);
-                let arg = match arg {
-                    None => {
-                        let i = next_arg_iter.next().expect("missing argument");
-                        let index = Index::from(i);
-                        used_positional_args.insert(i);
-                        quote!(__stylish_positional_args.#index)
-                    }
-                    Some(FormatArgRef::Positional(i)) => {
-                        let index = Index::from(i);
-                        if i >= num_positional_args {
-                            panic!("missing positional argument {i}");
-                        }
-                        used_positional_args.insert(i);
-                        quote!(__stylish_positional_args.#index)
-                    }
-                    Some(FormatArgRef::Named(name)) => {
-                        if let Some(&i) = named_args_names.get(name) {
-                            let index = Index::from(i);
-                            used_named_args.insert(name.to_owned());
-                            quote!(__stylish_named_args.#index)
-                        } else {
-                            let i = implicit_named_args_values.len();
-                            implicit_named_args_values.push(ExprPath {
-                                attrs: Vec::new(),
-                                qself: None,
-                                path: Ident::new_raw(
-                                    name,
-                                    Span::call_site().resolved_at(format_string.span()),
-                                )
-                                .into(),
-                            });
-                            let index = Index::from(i);
-                            quote!(__stylish_implicit_named_args.#index)
-                        }
+                let arg = quote! {
+                    #export::Padded {
+                        value: #arg,
+                        classes: &[#(#classes),*],
+                        style: #style,
                     }
                 };
-                let arg = (format_trait, arg);
-                let arg = Scoped::new(&export, &arg);
-                quote! {
-                    #export::Display::fmt(
-                        &#arg,
-                        &mut #export::Formatter::with_args(
-                            __stylish_formatter,
-                            #formatter_args,
-                            #style
-                        ),
-                    )?
-                }
+                (outer_attributes.classes, outer_attributes.style, arg)
             }
-        })
-        .collect();
-    let unused_positional_args =
-        &HashSet::from_iter(0..num_positional_args) - &used_positional_args;
-    let unused_named_args =
-        &HashSet::from_iter(named_args_names.keys().cloned()) - &used_named_args;
-    if !unused_positional_args.is_empty() || !unused_named_args.is_empty() {
-        panic!("unused formatting arguments");
+            _ => {
+                let classes = [outer_attributes.classes, attributes.classes].concat();
+                let style = outer_attributes.style.merge(attributes.style);
+                (classes, style, arg)
+            }
+        };
+        let formatter = classes.iter().fold(
+            quote!(__stylish_formatter),
+            |formatter, class| quote!(&mut #export::Formatter::with_class(#formatter, #class)),
+        );
+        let style = Scoped::new(&export, &style);
+        let arg = (format_trait, arg);
+        let arg = Scoped::new(&export, &arg);
+        statements.push(quote! {
+            #export::Display::fmt(
+                &#arg,
+                &mut #export::Formatter::with_args(
+                    #formatter,
+                    #formatter_args,
+                    #style
+                ),
+            )?
+        });
     }
+    unused_args(
+        positional_args
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !used_positional_args.contains(i))
+            .map(|(_, expr)| expr),
+        named_args_values
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !used_named_args.contains(i))
+            .map(|(_, expr)| expr),
+    )?;
+    let positional_args = quote! { (#(&#positional_args,)*) };
+    let named_args_values = quote! { (#(&#named_args_values,)*) };
     let implicit_named_args = quote! {
         (#(&#implicit_named_args_values,)*)
     };
-    quote! {
+    Ok(quote! {
         #export::Arguments {
             f: &match (#positional_args, #named_args_values, #implicit_named_args) {
                 (__stylish_positional_args, __stylish_named_args, __stylish_implicit_named_args) => {
@@ -221,7 +416,7 @@ fn format_args_impl(
                 }
             }
         }
-    }
+    })
 }
 
 /// Internal implementation details of
@@ -229,7 +424,7 @@ fn format_args_impl(
 #[proc_macro]
 pub fn format_args(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     format_args_impl(parse_macro_input!(input as ArgsInput))
-        .into_token_stream()
+        .unwrap_or_else(compile_error)
         .into()
 }
 
@@ -240,6 +435,257 @@ pub fn format_args_nl(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     let input = parse_macro_input!(input as ArgsInput);
     let format = LitStr::new(&(input.format.value() + "\n"), input.format.span());
     format_args_impl(ArgsInput { format, ..input })
-        .into_token_stream()
+        .unwrap_or_else(compile_error)
+        .into()
+}
+
+struct StyleInput {
+    krate: Path,
+    attributes: TokenStream,
+}
+
+impl syn::parse::Parse for StyleInput {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        input.parse::<Token![crate]>()?;
+        input.parse::<Token![=]>()?;
+        let krate = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let attributes = input.parse()?;
+        Ok(Self { krate, attributes })
+    }
+}
+
+/// Parse the attributes given to `style!` or `style_diff!`, either as a string
+/// literal or directly as tokens.
+fn parse_style(attributes: &TokenStream) -> Result<StyleDiff> {
+    if let Ok(lit) = syn::parse2::<LitStr>(attributes.clone()) {
+        let source = lit.value();
+        return source.parse().map_err(|err: ParseStyleError| {
+            syn::Error::new(format_span(&lit, err.span()), style_error(&err, &source))
+        });
+    }
+
+    // Whitespace is insignificant in attributes, but token stringification
+    // inserts it within values like `#ff8800` or `bright-red`
+    let tokens: Vec<(String, TokenTree)> = attributes
+        .clone()
+        .into_iter()
+        .map(|token| {
+            let text = token.to_string();
+            (text.chars().filter(|c| !c.is_whitespace()).collect(), token)
+        })
+        .collect();
+    let source: String = tokens.iter().map(|(text, _)| &**text).collect();
+    source.parse().map_err(|err: ParseStyleError| {
+        // Point at the tokens the error's span (or its position, if empty) overlaps
+        let span = err.span();
+        let end = span.end.max(span.start + 1);
+        let mut offset = 0;
+        let overlapping: TokenStream = tokens
+            .iter()
+            .filter(|(text, _)| {
+                let start = offset;
+                offset += text.len();
+                start < end && offset > span.start
+            })
+            .map(|(_, token)| token.clone())
+            .collect();
+        let message = style_error(&err, &source);
+        if overlapping.is_empty() {
+            syn::Error::new_spanned(attributes, message)
+        } else {
+            syn::Error::new_spanned(overlapping, message)
+        }
+    })
+}
+
+/// Internal implementation details of
+/// [`stylish_core::style!`](https://docs.rs/stylish-core/latest/stylish_core/macro.style.html).
+#[proc_macro]
+pub fn style(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let StyleInput { krate, attributes } = parse_macro_input!(input as StyleInput);
+    let export: syn::Path = syn::parse_quote!(#krate::𓀄);
+    match parse_style(&attributes) {
+        Ok(diff) => Scoped::new(&export, &Style::default().with(diff))
+            .into_token_stream()
+            .into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// Internal implementation details of
+/// [`stylish_core::style_diff!`](https://docs.rs/stylish-core/latest/stylish_core/macro.style_diff.html).
+#[proc_macro]
+pub fn style_diff(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let StyleInput { krate, attributes } = parse_macro_input!(input as StyleInput);
+    let export: syn::Path = syn::parse_quote!(#krate::𓀄);
+    match parse_style(&attributes) {
+        Ok(diff) => Scoped::new(&export, &diff).into_token_stream().into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// The text of a literal argument to `lit!`, as it would be displayed.
+fn lit_text(expr: &Expr) -> Result<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Str(lit) => Ok(lit.value()),
+            Lit::Char(lit) => Ok(lit.value().to_string()),
+            Lit::Int(lit) => Ok(lit.base10_digits().to_owned()),
+            Lit::Bool(lit) => Ok(lit.value.to_string()),
+            _ => Err(syn::Error::new_spanned(
+                lit,
+                "only string, character, integer and boolean literals can be used in `lit!`",
+            )),
+        },
+        Expr::Group(group) => lit_text(&group.expr),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "only literals can be used as arguments to `lit!`",
+        )),
+    }
+}
+
+fn lit_impl(
+    ArgsInput {
+        krate,
+        format,
+        positional_args,
+        named_args,
+    }: ArgsInput,
+) -> Result<TokenStream> {
+    let krate = krate.expect("base crate not specified (are you using stylish-macros directly instead of through stylish-core?)");
+    let export: syn::Path = syn::parse_quote!(#krate::𓀄);
+
+    let source = format.value();
+    let parsed = parse_format(&format, &source)?;
+    let num_implicit_placeholders = parsed
+        .pieces
+        .iter()
+        .filter(|piece| matches!(piece, Piece::Arg(FormatArg { arg: None, .. })))
+        .count();
+
+    let mut next_arg = 0;
+    let mut used_positional_args = HashSet::new();
+    let mut used_named_args = HashSet::new();
+    let mut spans: Vec<(String, StyleDiff)> = Vec::new();
+    for piece in parsed.pieces {
+        let (text, style) = match piece {
+            Piece::Lit(lit) => (lit.to_owned(), StyleDiff::default()),
+            Piece::Arg(
+                ref placeholder @ FormatArg {
+                    arg,
+                    format_spec:
+                        FormatSpec {
+                            formatter_args,
+                            ref outer_attributes,
+                            ref attributes,
+                            format_trait,
+                        },
+                    ..
+                },
+            ) => {
+                let error = |message: &str| {
+                    syn::Error::new(arg_span(&format, &source, placeholder.source), message)
+                };
+                if !outer_attributes.classes.is_empty() || !attributes.classes.is_empty() {
+                    return Err(error("theme classes cannot be used in `lit!`"));
+                }
+                let FormatterArgs {
+                    fill: _,
+                    align: None,
+                    sign: None,
+                    alternate: false,
+                    zero: false,
+                    width: None,
+                    precision: None,
+                    debug_hex: None,
+                } = formatter_args
+                else {
+                    return Err(error("only style attributes are supported in `lit!`"));
+                };
+                if !matches!(format_trait, FormatTrait::Display) {
+                    return Err(error("only `Display` arguments are supported in `lit!`"));
+                }
+                let expr = match arg {
+                    None => {
+                        next_arg += 1;
+                        used_positional_args.insert(next_arg - 1);
+                        positional_args.get(next_arg - 1).ok_or_else(|| {
+                            missing_positional_args(
+                                arg_span(&format, &source, placeholder.source),
+                                num_implicit_placeholders,
+                                positional_args.len(),
+                            )
+                        })?
+                    }
+                    Some(FormatArgRef::Positional(i)) => {
+                        used_positional_args.insert(i);
+                        positional_args.get(i).ok_or_else(|| {
+                            invalid_positional_arg(
+                                arg_span(&format, &source, placeholder.source),
+                                i,
+                                positional_args.len(),
+                            )
+                        })?
+                    }
+                    Some(FormatArgRef::Named(name)) => {
+                        let i = named_args
+                            .iter()
+                            .position(|(ident, _)| ident == name)
+                            .ok_or_else(|| {
+                                error(&format!(
+                                    "there is no argument named `{name}`, `lit!` can only use \
+                                     literal arguments"
+                                ))
+                            })?;
+                        used_named_args.insert(i);
+                        &named_args[i].1
+                    }
+                };
+                (
+                    lit_text(expr)?,
+                    outer_attributes.style.merge(attributes.style),
+                )
+            }
+        };
+        match spans.last_mut() {
+            Some((last, last_style)) if *last_style == style => last.push_str(&text),
+            _ if text.is_empty() => {}
+            _ => spans.push((text, style)),
+        }
+    }
+
+    unused_args(
+        positional_args
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !used_positional_args.contains(i))
+            .map(|(_, expr)| expr),
+        named_args
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !used_named_args.contains(i))
+            .map(|(_, (_, expr))| expr),
+    )?;
+
+    let spans = spans.iter().map(|(text, style)| {
+        let style = Scoped::new(&export, style);
+        quote!((#text, #style))
+    });
+    Ok(quote! {
+        {
+            const SPANS: &[(&str, #export::StyleDiff)] = &[#(#spans,)*];
+            #export::StyledStr::new(SPANS)
+        }
+    })
+}
+
+/// Internal implementation details of
+/// [`stylish_core::lit!`](https://docs.rs/stylish-core/latest/stylish_core/macro.lit.html).
+#[proc_macro]
+pub fn lit(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    lit_impl(parse_macro_input!(input as ArgsInput))
+        .unwrap_or_else(compile_error)
         .into()
 }
@@ -4,9 +4,9 @@
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use stylish_style::{Color, Foreground, Intensity, StyleDiff};
+use stylish_style::{Color, Foreground, Intensity, Style, StyleDiff, Underline};
 
-use crate::format::{Align, Count, DebugHex, FormatTrait, FormatterArgs, Sign};
+use crate::format::{Align, DebugHex, FormatTrait, FormatterArgs, Sign};
 
 fn quote_opt<'a, T: 'a>(opt: Scoped<'a, Option<T>>) -> TokenStream
 where
@@ -47,6 +47,12 @@ impl<'a, T> Scoped<'a, T> {
     }
 }
 
+impl<'a> ToTokens for Scoped<'a, TokenStream> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.as_ref().to_tokens(tokens)
+    }
+}
+
 impl<'a> ToTokens for Scoped<'a, Color> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let export = &self.export;
@@ -60,6 +66,17 @@ impl<'a> ToTokens for Scoped<'a, Color> {
             Color::Cyan => quote!(#export::Color::Cyan),
             Color::White => quote!(#export::Color::White),
             Color::Default => quote!(#export::Color::Default),
+            Color::Inherit => quote!(#export::Color::Inherit),
+            Color::BrightBlack => quote!(#export::Color::BrightBlack),
+            Color::BrightRed => quote!(#export::Color::BrightRed),
+            Color::BrightGreen => quote!(#export::Color::BrightGreen),
+            Color::BrightYellow => quote!(#export::Color::BrightYellow),
+            Color::BrightBlue => quote!(#export::Color::BrightBlue),
+            Color::BrightMagenta => quote!(#export::Color::BrightMagenta),
+            Color::BrightCyan => quote!(#export::Color::BrightCyan),
+            Color::BrightWhite => quote!(#export::Color::BrightWhite),
+            Color::Ansi256(index) => quote!(#export::Color::Ansi256(#index)),
+            Color::Rgb(r, g, b) => quote!(#export::Color::Rgb(#r, #g, #b)),
             color => unreachable!("unknown color {color:?}"),
         }
         .to_tokens(tokens)
@@ -79,6 +96,22 @@ impl<'a> ToTokens for Scoped<'a, Intensity> {
     }
 }
 
+impl<'a> ToTokens for Scoped<'a, Underline> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let export = &self.export;
+        match self.as_ref() {
+            Underline::None => quote!(#export::Underline::None),
+            Underline::Single => quote!(#export::Underline::Single),
+            Underline::Double => quote!(#export::Underline::Double),
+            Underline::Curly => quote!(#export::Underline::Curly),
+            Underline::Dotted => quote!(#export::Underline::Dotted),
+            Underline::Dashed => quote!(#export::Underline::Dashed),
+            underline => unreachable!("unknown underline {underline:?}"),
+        }
+        .to_tokens(tokens)
+    }
+}
+
 impl<'a> ToTokens for Scoped<'a, Foreground> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let export = &self.export;
@@ -88,31 +121,80 @@ impl<'a> ToTokens for Scoped<'a, Foreground> {
     }
 }
 
-impl<'a> ToTokens for Scoped<'a, StyleDiff> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        let export = &self.export;
+impl<'a> Scoped<'a, StyleDiff> {
+    /// The const builder method calls that apply the changes in this diff.
+    fn builders(&self) -> TokenStream {
         let StyleDiff {
             foreground,
             background,
             intensity,
+            italic,
+            underline,
+            underline_color,
+            strikethrough,
+            reverse,
+            blink,
+            hidden,
+            overline,
             ..
         } = self.as_ref();
-        let mut inner = TokenStream::new();
-        quote!(let mut diff = #export::StyleDiff::default();).to_tokens(&mut inner);
+        let mut tokens = TokenStream::new();
         if let Some(foreground) = foreground {
             let foreground = self.scope(foreground);
-            quote!(diff.foreground = Some(#foreground);).to_tokens(&mut inner);
+            quote!(.foreground(#foreground)).to_tokens(&mut tokens);
         }
         if let Some(background) = background {
             let background = self.scope(background);
-            quote!(diff.background = Some(#background);).to_tokens(&mut inner);
+            quote!(.background(#background)).to_tokens(&mut tokens);
         }
         if let Some(intensity) = intensity {
             let intensity = self.scope(intensity);
-            quote!(diff.intensity = Some(#intensity);).to_tokens(&mut inner);
+            quote!(.intensity(#intensity)).to_tokens(&mut tokens);
+        }
+        if let Some(italic) = italic {
+            quote!(.italic(#italic)).to_tokens(&mut tokens);
+        }
+        if let Some(underline) = underline {
+            let underline = self.scope(underline);
+            quote!(.underline(#underline)).to_tokens(&mut tokens);
+        }
+        if let Some(underline_color) = underline_color {
+            let underline_color = self.scope(underline_color);
+            quote!(.underline_color(#underline_color)).to_tokens(&mut tokens);
+        }
+        if let Some(strikethrough) = strikethrough {
+            quote!(.strikethrough(#strikethrough)).to_tokens(&mut tokens);
+        }
+        if let Some(reverse) = reverse {
+            quote!(.reverse(#reverse)).to_tokens(&mut tokens);
+        }
+        if let Some(blink) = blink {
+            quote!(.blink(#blink)).to_tokens(&mut tokens);
+        }
+        if let Some(hidden) = hidden {
+            quote!(.hidden(#hidden)).to_tokens(&mut tokens);
         }
-        quote!(diff).to_tokens(&mut inner);
-        quote!({ #inner }).to_tokens(tokens);
+        if let Some(overline) = overline {
+            quote!(.overline(#overline)).to_tokens(&mut tokens);
+        }
+        tokens
+    }
+}
+
+impl<'a> ToTokens for Scoped<'a, StyleDiff> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let export = &self.export;
+        let builders = self.builders();
+        quote!(#export::StyleDiff::new() #builders).to_tokens(tokens);
+    }
+}
+
+impl<'a> ToTokens for Scoped<'a, Style> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let export = &self.export;
+        let diff = self.as_ref().diff_from(Style::default());
+        let builders = self.scope(&diff).builders();
+        quote!(#export::Style::new() #builders).to_tokens(tokens);
     }
 }
 
@@ -139,15 +221,6 @@ impl<'a> ToTokens for Scoped<'a, Sign> {
     }
 }
 
-impl<'a, 'b: 'a> ToTokens for Scoped<'a, Count<'b>> {
-    fn to_tokens(&self, tokens: &mut TokenStream) {
-        match self.as_ref() {
-            Count::Parameter(_value) => todo!("parameter reference counts are not yet supported"),
-            Count::Integer(value) => quote!(&#value).to_tokens(tokens),
-        }
-    }
-}
-
 impl<'a> ToTokens for Scoped<'a, DebugHex> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let export = &self.export;
@@ -159,18 +232,27 @@ impl<'a> ToTokens for Scoped<'a, DebugHex> {
     }
 }
 
-impl<'a, 'b: 'a> ToTokens for Scoped<'a, FormatterArgs<'b>> {
+/// The formatter arguments, along with expressions for the width and
+/// precision (as they may refer to other arguments).
+impl<'a, 'b: 'a> ToTokens
+    for Scoped<'a, (FormatterArgs<'b>, Option<TokenStream>, Option<TokenStream>)>
+{
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let export = &self.export;
-        let FormatterArgs {
-            align,
-            sign,
-            alternate,
-            zero,
+        let (
+            FormatterArgs {
+                fill,
+                align,
+                sign,
+                alternate,
+                zero,
+                width: _,
+                precision: _,
+                debug_hex,
+            },
             width,
             precision,
-            debug_hex,
-        } = self.as_ref();
+        ) = self.as_ref();
         let align = quote_opt(self.scope(align));
         let sign = quote_opt(self.scope(sign));
         let width = quote_opt(self.scope(width));
@@ -178,6 +260,7 @@ impl<'a, 'b: 'a> ToTokens for Scoped<'a, FormatterArgs<'b>> {
         let debug_hex = quote_opt(self.scope(debug_hex));
         (quote! {
             &#export::FormatterArgs {
+                fill: #fill,
                 align: #align,
                 sign: #sign,
                 alternate: #alternate,
@@ -203,7 +286,7 @@ impl ToTokens for FormatTrait {
             FormatTrait::Binary => quote!(Binary),
             FormatTrait::LowerExp => quote!(LowerExp),
             FormatTrait::UpperExp => quote!(UpperExp),
-            FormatTrait::Stylish => unreachable!(),
+            FormatTrait::Stylish | FormatTrait::StylishDebug => unreachable!(),
         }
         .to_tokens(tokens)
     }
@@ -214,6 +297,16 @@ impl<'a> ToTokens for Scoped<'a, (FormatTrait, TokenStream)> {
         let export = &self.export;
         match self.as_ref() {
             (FormatTrait::Stylish, arg) => arg.to_tokens(tokens),
+            // Uses `stylish::Debug` if the argument implements it, otherwise
+            // falls back to `core::fmt::Debug`
+            (FormatTrait::StylishDebug, arg) => quote! {
+                {
+                    #[allow(unused_imports)]
+                    use #export::{ViaStdDebug as _, ViaStylishDebug as _};
+                    (&#export::DebugArg(#arg)).__stylish_debug()
+                }
+            }
+            .to_tokens(tokens),
             (format_trait, arg) => {
                 let inner = quote! {
                     #export::StdFmt {
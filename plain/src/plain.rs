@@ -12,12 +12,40 @@ use stylish_core::{Result, Style, Write};
 #[derive(Clone, Debug, Default)]
 pub struct Plain<T> {
     inner: T,
+    render_links: bool,
 }
 
 impl<T: core::fmt::Write> Plain<T> {
     /// Wrap the given output stream in this adaptor.
     pub fn new(inner: T) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            render_links: false,
+        }
+    }
+
+    /// Set whether hyperlinks are rendered by following their content with
+    /// the URL in parentheses, by default only the content is written.
+    ///
+    /// ```rust
+    /// struct Docs;
+    ///
+    /// impl stylish::Display for Docs {
+    ///     fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+    ///         f.link("https://example.com", |f| f.write_str("docs"))
+    ///     }
+    /// }
+    ///
+    /// let mut writer = stylish::Plain::new(String::new()).render_links(true);
+    /// stylish::write!(writer, "see the {:s}", Docs)?;
+    /// assert_eq!(writer.into_inner(), "see the docs (https://example.com)");
+    /// # Ok::<(), core::fmt::Error>(())
+    /// ```
+    pub fn render_links(self, render_links: bool) -> Self {
+        Self {
+            render_links,
+            ..self
+        }
     }
 
     /// Inherent delegation to
@@ -37,4 +65,11 @@ impl<T: core::fmt::Write> Write for Plain<T> {
     fn write_str(&mut self, s: &str, _style: Style) -> Result {
         self.inner.write_str(s)
     }
+
+    fn end_link(&mut self, url: &str) -> Result {
+        if self.render_links {
+            write!(self.inner, " ({url})")?;
+        }
+        Ok(())
+    }
 }
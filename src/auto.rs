@@ -0,0 +1,241 @@
+use std::{ffi::OsString, fmt, io::IsTerminal, str::FromStr};
+
+use stylish_core::{
+    io::{Result, Write},
+    Arguments, ColorDepth, Style,
+};
+
+use crate::io::{Ansi, Plain};
+
+/// Whether to style output, usually chosen by a `--color` command line flag.
+///
+/// Parses from and displays as `auto`, `always` or `never`.
+///
+/// ```rust
+/// use stylish::{io::ColorChoice, ColorDepth};
+///
+/// let choice: ColorChoice = "auto".parse()?;
+/// assert_eq!(choice, ColorChoice::Auto);
+/// assert_eq!(choice.to_string(), "auto");
+///
+/// let env = |name: &str| (name == "TERM").then(|| "xterm-256color".into());
+/// assert_eq!(choice.resolve(true, env), Some(ColorDepth::Ansi256));
+/// assert_eq!(choice.resolve(false, env), None);
+/// assert_eq!(ColorChoice::Always.resolve(false, env), Some(ColorDepth::Ansi256));
+/// # Ok::<(), stylish::io::ParseColorChoiceError>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum ColorChoice {
+    /// Style output if the stream is a terminal and the environment allows it
+    #[default]
+    Auto,
+    /// Always style output
+    Always,
+    /// Never style output
+    Never,
+}
+
+/// An error returned when parsing a [`ColorChoice`] fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseColorChoiceError(());
+
+impl ColorChoice {
+    /// Decide how to style output to a stream, returning the [`ColorDepth`]
+    /// to write ANSI escape codes with, or [`None`] to write plain output.
+    ///
+    /// `is_terminal` is whether the stream is a terminal and `var` looks up
+    /// environment variables (normally [`std::env::var_os`]).
+    ///
+    /// With [`ColorChoice::Auto`] output is plain if `NO_COLOR` is set to a
+    /// non-empty value, otherwise it is styled if `CLICOLOR_FORCE` is set to a
+    /// value other than `0`, otherwise it is only styled if the stream is a
+    /// terminal, `TERM` is not `dumb` and `CLICOLOR` is not `0`.
+    ///
+    /// When styled the depth is [`ColorDepth::TrueColor`] if `COLORTERM` is
+    /// `truecolor` or `24bit`, [`ColorDepth::Ansi256`] if `TERM` contains
+    /// `256color`, and [`ColorDepth::Ansi16`] otherwise.
+    pub fn resolve(
+        self,
+        is_terminal: bool,
+        var: impl Fn(&str) -> Option<OsString>,
+    ) -> Option<ColorDepth> {
+        let set = |name| var(name).filter(|value| !value.is_empty());
+        let term = var("TERM").unwrap_or_default();
+
+        let styled = match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto if set("NO_COLOR").is_some() => false,
+            ColorChoice::Auto if set("CLICOLOR_FORCE").is_some_and(|value| value != "0") => true,
+            ColorChoice::Auto => {
+                is_terminal && term != "dumb" && var("CLICOLOR").is_none_or(|value| value != "0")
+            }
+        };
+
+        styled.then(|| {
+            let colorterm = var("COLORTERM").unwrap_or_default();
+            if colorterm == "truecolor" || colorterm == "24bit" {
+                ColorDepth::TrueColor
+            } else if term.to_string_lossy().contains("256color") {
+                ColorDepth::Ansi256
+            } else {
+                ColorDepth::Ansi16
+            }
+        })
+    }
+}
+
+impl FromStr for ColorChoice {
+    type Err = ParseColorChoiceError;
+
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(ParseColorChoiceError(())),
+        }
+    }
+}
+
+impl fmt::Display for ColorChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ColorChoice::Auto => "auto",
+            ColorChoice::Always => "always",
+            ColorChoice::Never => "never",
+        })
+    }
+}
+
+impl fmt::Display for ParseColorChoiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("expected `auto`, `always` or `never`")
+    }
+}
+
+impl std::error::Error for ParseColorChoiceError {}
+
+#[derive(Clone, Debug)]
+enum Inner<T: std::io::Write> {
+    Ansi(Ansi<T>, ColorDepth),
+    Plain(Plain<T>),
+}
+
+/// An adaptor that writes [`stylish`] attributed data to an output stream as
+/// either ANSI escape codes or plain text, depending on whether the stream
+/// and environment support styling, see [`ColorChoice::resolve`].
+///
+/// ```rust
+/// use stylish::ColorDepth;
+///
+/// let mut writer = stylish::io::Auto::with_depth(Vec::new(), Some(ColorDepth::Ansi16));
+/// stylish::write!(writer, "Hello {:(fg=#ff0000)}", "Ferris")?;
+/// assert_eq!(writer.finish()?, b"Hello \x1b[91mFerris\x1b[0m");
+///
+/// let mut writer = stylish::io::Auto::with_depth(Vec::new(), None);
+/// stylish::write!(writer, "Hello {:(fg=#ff0000)}", "Ferris")?;
+/// assert_eq!(writer.finish()?, b"Hello Ferris");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Auto<T: std::io::Write> {
+    inner: Inner<T>,
+}
+
+impl<T: std::io::Write + IsTerminal> Auto<T> {
+    /// Wrap the given output stream in this adaptor, deciding whether to style
+    /// it from `choice`, whether it is a terminal, and the process
+    /// environment.
+    pub fn new(inner: T, choice: ColorChoice) -> Self {
+        let depth = choice.resolve(inner.is_terminal(), |name| std::env::var_os(name));
+        Self::with_depth(inner, depth)
+    }
+}
+
+impl<T: std::io::Write> Auto<T> {
+    /// Wrap the given output stream in this adaptor, writing ANSI escape codes
+    /// with the given `depth`, or plain text if it is [`None`].
+    pub fn with_depth(inner: T, depth: Option<ColorDepth>) -> Self {
+        let inner = match depth {
+            Some(depth) => Inner::Ansi(Ansi::new(inner).depth(depth), depth),
+            None => Inner::Plain(Plain::new(inner)),
+        };
+        Self { inner }
+    }
+
+    /// The color depth being written, or [`None`] if writing plain text.
+    pub fn color_depth(&self) -> Option<ColorDepth> {
+        match &self.inner {
+            Inner::Ansi(_, depth) => Some(*depth),
+            Inner::Plain(_) => None,
+        }
+    }
+
+    /// Inherent delegation to
+    /// [`stylish::io::Write::write_fmt`](stylish_core::io::Write::write_fmt) to
+    /// not require a trait import.
+    pub fn write_fmt(&mut self, args: Arguments<'_>) -> Result<()> {
+        Write::write_fmt(self, args)
+    }
+
+    /// Ensure the output stream is reset back to the default style and return
+    /// it, if you don't call this the stream will be left in whatever style
+    /// the last output data was.
+    pub fn finish(self) -> Result<T> {
+        match self.inner {
+            Inner::Ansi(ansi, _) => ansi.finish(),
+            Inner::Plain(plain) => Ok(plain.into_inner()),
+        }
+    }
+}
+
+impl<T: std::io::Write> Write for Auto<T> {
+    fn write(&mut self, s: &[u8], style: Style) -> Result<usize> {
+        match &mut self.inner {
+            Inner::Ansi(ansi, _) => ansi.write(s, style),
+            Inner::Plain(plain) => plain.write(s, style),
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        match &mut self.inner {
+            Inner::Ansi(ansi, _) => Write::flush(ansi),
+            Inner::Plain(plain) => Write::flush(plain),
+        }
+    }
+
+    fn write_all(&mut self, s: &[u8], style: Style) -> Result<()> {
+        match &mut self.inner {
+            Inner::Ansi(ansi, _) => ansi.write_all(s, style),
+            Inner::Plain(plain) => plain.write_all(s, style),
+        }
+    }
+
+    fn start_link(&mut self, url: &str) -> Result<()> {
+        match &mut self.inner {
+            Inner::Ansi(ansi, _) => ansi.start_link(url),
+            Inner::Plain(plain) => plain.start_link(url),
+        }
+    }
+
+    fn end_link(&mut self, url: &str) -> Result<()> {
+        match &mut self.inner {
+            Inner::Ansi(ansi, _) => ansi.end_link(url),
+            Inner::Plain(plain) => plain.end_link(url),
+        }
+    }
+}
+
+/// Wrap `inner` in an [`Auto`] adaptor with [`ColorChoice::Auto`], styling
+/// the output only if it is a terminal and the environment allows it.
+///
+/// ```rust,no_run
+/// let mut writer = stylish::io::auto(std::io::stdout());
+/// stylish::writeln!(writer, "Hello {:(fg=red)}", "Ferris")?;
+/// writer.finish()?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn auto<T: std::io::Write + IsTerminal>(inner: T) -> Auto<T> {
+    Auto::new(inner, ColorChoice::Auto)
+}
@@ -17,16 +17,26 @@ use core::fmt as doc_fmt;
 #[cfg(all(doc, feature = "std"))]
 use std::fmt as doc_fmt;
 
+#[cfg(all(feature = "std", feature = "ansi", feature = "plain"))]
+mod auto;
+
+#[cfg(feature = "gradient")]
+pub use stylish_core::Gradient;
 #[cfg(all(feature = "alloc", feature = "macros"))]
 pub use stylish_core::ToStylishString;
 #[cfg(feature = "alloc")]
 pub use stylish_core::{format, String};
 #[cfg(feature = "macros")]
-pub use stylish_core::{format_args, write, writeln};
+pub use stylish_core::{format_args, lit, style, style_diff, write, writeln};
 pub use stylish_core::{
-    Arguments, Background, Color, Display, Error, Foreground, Formatter, Intensity, Restyle,
-    Result, Style, StyleDiff, Write,
+    Arguments, Background, Blink, Color, ColorDepth, Debug, DebugList, DebugMap, DebugSet,
+    DebugStruct, DebugTuple, Deficiency, Display, Error, Foreground, Formatter, Hidden, Hsl, Hsv,
+    Intensity, Italic, MinContrast, Oklch, Overline, Palette, ParseStyleError, ParseStyleErrorKind,
+    Reset, Restyle, RestyleFn, Restyled, Result, Reverse, SgrList, Strikethrough, Style, StyleDiff,
+    StyledStr, Swap, Theme, Themed, Underline, UnderlineColor, When, Write,
 };
+#[cfg(feature = "std")]
+pub use stylish_core::{LsColors, StyledPath};
 
 #[cfg(feature = "std")]
 pub mod io {
@@ -35,7 +45,10 @@ pub mod io {
 
     #[cfg(feature = "ansi")]
     pub use stylish_ansi::io::Ansi;
-    pub use stylish_core::io::{Error, ErrorKind, Result, Write};
+    pub use stylish_core::io::{Error, ErrorKind, Restyled, Result, Themed, Write};
+
+    #[cfg(all(feature = "ansi", feature = "plain"))]
+    pub use crate::auto::{auto, Auto, ColorChoice, ParseColorChoiceError};
 
     #[cfg(feature = "ansi")]
     /// An alias for [`stylish::io::Ansi::new`] for more succinct code.
@@ -144,3 +157,16 @@ pub mod plain {
     #[cfg(feature = "alloc")]
     pub use stylish_plain::format;
 }
+
+#[cfg(feature = "theme")]
+pub mod theme {
+    //! Helpers for loading themes and color schemes from files.
+
+    #[cfg(feature = "theme-base16")]
+    pub use stylish_theme::base16_palette;
+    #[cfg(feature = "theme-json")]
+    pub use stylish_theme::from_json;
+    #[cfg(feature = "theme-toml")]
+    pub use stylish_theme::from_toml;
+    pub use stylish_theme::Error;
+}
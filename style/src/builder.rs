@@ -0,0 +1,107 @@
+use crate::{Color, Intensity, Style, StyleDiff, Underline};
+
+impl Style {
+    /// The default style, the same as [`Style::default`] but usable in `const`
+    /// contexts.
+    ///
+    /// Combined with the builder methods named after each field this allows
+    /// defining styles as constants, see also the `stylish::style!` macro.
+    ///
+    /// ```rust
+    /// use stylish::{Color, Foreground, Intensity, Style};
+    ///
+    /// const ERROR: Style = Style::new().foreground(Color::Red).intensity(Intensity::Bold);
+    ///
+    /// assert_eq!(
+    ///     ERROR,
+    ///     Style::default().with(Foreground(Color::Red)).with(Intensity::Bold),
+    /// );
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            foreground: Color::Default,
+            background: Color::Default,
+            intensity: Intensity::Normal,
+            italic: false,
+            underline: Underline::None,
+            underline_color: Color::Default,
+            strikethrough: false,
+            reverse: false,
+            blink: false,
+            hidden: false,
+            overline: false,
+        }
+    }
+}
+
+impl StyleDiff {
+    /// A diff that makes no changes, the same as [`StyleDiff::default`] but
+    /// usable in `const` contexts.
+    ///
+    /// Combined with the builder methods named after each field this allows
+    /// defining diffs as constants, see also the `stylish::style_diff!` macro.
+    ///
+    /// ```rust
+    /// use stylish::{Color, StyleDiff};
+    ///
+    /// const WARNING: StyleDiff = StyleDiff::new().foreground(Color::Yellow).italic(true);
+    ///
+    /// assert_eq!(WARNING, "fg=yellow, italic".parse()?);
+    /// # Ok::<(), stylish::ParseStyleError>(())
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            foreground: None,
+            background: None,
+            intensity: None,
+            italic: None,
+            underline: None,
+            underline_color: None,
+            strikethrough: None,
+            reverse: None,
+            blink: None,
+            hidden: None,
+            overline: None,
+        }
+    }
+}
+
+macro_rules! builders {
+    ($($field:ident: $ty:ty,)*) => {
+        impl Style {
+            $(
+                #[doc = concat!("Set [`Style::", stringify!($field), "`], returning the updated style.")]
+                #[inline]
+                pub const fn $field(self, $field: $ty) -> Self {
+                    Self { $field, ..self }
+                }
+            )*
+        }
+
+        impl StyleDiff {
+            $(
+                #[doc = concat!("Set [`StyleDiff::", stringify!($field), "`] to change to `", stringify!($field), "`, returning the updated diff.")]
+                #[inline]
+                pub const fn $field(self, $field: $ty) -> Self {
+                    Self { $field: Some($field), ..self }
+                }
+            )*
+        }
+    };
+}
+
+builders! {
+    foreground: Color,
+    background: Color,
+    intensity: Intensity,
+    italic: bool,
+    underline: Underline,
+    underline_color: Color,
+    strikethrough: bool,
+    reverse: bool,
+    blink: bool,
+    hidden: bool,
+    overline: bool,
+}
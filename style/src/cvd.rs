@@ -0,0 +1,88 @@
+use crate::{
+    math::{from_linear, from_unit, to_linear, to_unit},
+    Color, Palette, Restyle, Style,
+};
+
+/// A form of color vision deficiency, used as a [`Restyle`] implementor to
+/// simulate how colors appear to people with it.
+///
+/// This converts every color with a known RGB value (using the xterm palette
+/// for named colors) to the RGB color it is perceived as, which is useful for
+/// checking that output is still readable. To make output more readable for
+/// people with a deficiency, use [`Palette::OKABE_ITO`] instead.
+///
+/// The simulation uses the model from [Machado et al. 2009] at full severity.
+///
+/// ```rust
+/// use stylish::{Color, Deficiency, Foreground, Style};
+///
+/// let red = Style::default().with(Foreground(Color::Red));
+/// let green = Style::default().with(Foreground(Color::Green));
+///
+/// // Red and green are much harder to tell apart with deuteranopia
+/// let (red, green) = (red.with(Deficiency::Deuteranopia), green.with(Deficiency::Deuteranopia));
+/// assert_eq!(red.foreground, Color::Rgb(0x82, 0x73, 0x00));
+/// assert_eq!(green.foreground, Color::Rgb(0xc0, 0xac, 0x2d));
+/// ```
+///
+/// [Machado et al. 2009]: https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Deficiency {
+    /// Missing red cones (red-green deficiency)
+    Protanopia,
+    /// Missing green cones (red-green deficiency, the most common form)
+    Deuteranopia,
+    /// Missing blue cones (blue-yellow deficiency)
+    Tritanopia,
+}
+
+impl Deficiency {
+    /// The matrix to apply to linear RGB values to simulate this deficiency.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Deficiency::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Deficiency::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Deficiency::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+
+    /// Convert `color` to the color it is perceived as with this deficiency,
+    /// colors without a known RGB value are returned unchanged.
+    pub fn simulate(self, color: Color) -> Color {
+        let Some((r, g, b)) = Palette::XTERM.rgb(color) else {
+            return color;
+        };
+        let rgb = [r, g, b].map(|c| to_linear(to_unit(c)));
+        let [r, g, b] = self.matrix().map(|row| {
+            from_unit(from_linear(
+                (row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]).clamp(0.0, 1.0),
+            ))
+        });
+        Color::Rgb(r, g, b)
+    }
+}
+
+impl Restyle for Deficiency {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        Style {
+            foreground: self.simulate(style.foreground),
+            background: self.simulate(style.background),
+            underline_color: self.simulate(style.underline_color),
+            ..style
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{Color, Palette, Restyle, Style};
+
+/// The named colors in the order of the first 16 entries of the 256-color
+/// palette.
+pub(crate) const NAMED: [Color; 16] = [
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+    Color::BrightBlack,
+    Color::BrightRed,
+    Color::BrightGreen,
+    Color::BrightYellow,
+    Color::BrightBlue,
+    Color::BrightMagenta,
+    Color::BrightCyan,
+    Color::BrightWhite,
+];
+
+/// How many colors an output supports, used as a [`Restyle`] implementor to
+/// downsample every color in a [`Style`] to the nearest supported color.
+///
+/// Depths are ordered by how many colors they support, so
+/// `depth >= ColorDepth::Ansi256` checks whether 256-color output is
+/// available.
+///
+/// ```rust
+/// use stylish::{Background, Color, ColorDepth, Foreground, Intensity, Style};
+///
+/// let style = Style::default()
+///     .with(Foreground(Color::Rgb(0xff, 0x80, 0x00)))
+///     .with(Background(Color::Ansi256(17)))
+///     .with(Intensity::Bold);
+///
+/// assert_eq!(style.with(ColorDepth::TrueColor), style);
+/// assert_eq!(
+///     style.with(ColorDepth::Ansi256).foreground,
+///     Color::Ansi256(208)
+/// );
+/// assert_eq!(
+///     style.with(ColorDepth::Ansi16),
+///     Style::default()
+///         .with(Foreground(Color::Yellow))
+///         .with(Background(Color::Black))
+///         .with(Intensity::Bold),
+/// );
+/// assert_eq!(
+///     style.with(ColorDepth::NoColor),
+///     Style::default().with(Intensity::Bold)
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum ColorDepth {
+    /// No colors are supported, all colors are replaced with
+    /// [`Color::Default`] (other attributes are kept)
+    NoColor,
+    /// Only the 8 basic and 8 bright named colors are supported
+    Ansi16,
+    /// The 256-color indexed palette is supported
+    Ansi256,
+    /// 24-bit RGB colors are supported
+    #[default]
+    TrueColor,
+}
+
+/// The perceptual distance between two colors, using the "redmean"
+/// approximation which weights each channel by how sensitive human vision is
+/// to it.
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let mean = (u32::from(r1) + u32::from(r2)) / 2;
+    let r = u32::from(r1.abs_diff(r2));
+    let g = u32::from(g1.abs_diff(g2));
+    let b = u32::from(b1.abs_diff(b2));
+    (((512 + mean) * r * r) >> 8) + 4 * g * g + (((767 - mean) * b * b) >> 8)
+}
+
+/// The index of the candidate color closest to `rgb`.
+fn nearest(rgb: (u8, u8, u8), candidates: impl Iterator<Item = u8>) -> u8 {
+    candidates
+        .min_by_key(|&index| distance(rgb, Palette::XTERM.rgb(Color::Ansi256(index)).unwrap()))
+        .unwrap_or_default()
+}
+
+impl Color {
+    /// Convert an RGB color to the nearest color in entries `16..256` of the
+    /// 256-color palette, other colors are returned unchanged.
+    ///
+    /// The first 16 entries are skipped as they are commonly customized by
+    /// terminal color schemes, so may not display as the expected RGB value.
+    ///
+    /// ```rust
+    /// use stylish::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0xff, 0x00, 0x00).to_ansi256(), Color::Ansi256(196));
+    /// assert_eq!(Color::Rgb(0x80, 0x80, 0x80).to_ansi256(), Color::Ansi256(244));
+    /// assert_eq!(Color::Red.to_ansi256(), Color::Red);
+    /// ```
+    pub fn to_ansi256(self) -> Color {
+        match self {
+            Color::Rgb(r, g, b) => Color::Ansi256(nearest((r, g, b), 16..=255)),
+            color => color,
+        }
+    }
+
+    /// Convert an RGB or 256-color palette color to the nearest of the 16
+    /// named colors (using the xterm default values), other colors are
+    /// returned unchanged.
+    ///
+    /// ```rust
+    /// use stylish::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0xff, 0x10, 0x10).to_ansi16(), Color::BrightRed);
+    /// assert_eq!(Color::Ansi256(9).to_ansi16(), Color::BrightRed);
+    /// assert_eq!(Color::Ansi256(22).to_ansi16(), Color::Black);
+    /// assert_eq!(Color::Default.to_ansi16(), Color::Default);
+    /// ```
+    pub fn to_ansi16(self) -> Color {
+        match self {
+            Color::Ansi256(index @ 0..=15) => NAMED[usize::from(index)],
+            Color::Ansi256(_) | Color::Rgb(..) => {
+                let rgb = Palette::XTERM.rgb(self).unwrap();
+                NAMED[usize::from(nearest(rgb, 0..=15))]
+            }
+            color => color,
+        }
+    }
+}
+
+impl ColorDepth {
+    /// Convert `color` to the nearest color supported at this depth.
+    pub fn downsample(self, color: Color) -> Color {
+        match self {
+            ColorDepth::NoColor => match color {
+                Color::Inherit => Color::Inherit,
+                _ => Color::Default,
+            },
+            ColorDepth::Ansi16 => color.to_ansi16(),
+            ColorDepth::Ansi256 => color.to_ansi256(),
+            ColorDepth::TrueColor => color,
+        }
+    }
+}
+
+impl Restyle for ColorDepth {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        Style {
+            foreground: self.downsample(style.foreground),
+            background: self.downsample(style.background),
+            underline_color: self.downsample(style.underline_color),
+            ..style
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    depth::NAMED,
+    parse::{parse_rgb, parse_u8, ParseStyleError, ParseStyleErrorKind},
+    sgr::reset,
+    Color, Intensity, StyleDiff, Underline,
+};
+
+const COLORS: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+/// Parses a single color word, returning `Some(None)` for `normal` which
+/// leaves the color unchanged.
+fn color(word: &str) -> Option<Option<Color>> {
+    let named = |name: &str| {
+        COLORS
+            .iter()
+            .position(|color| color.eq_ignore_ascii_case(name))
+    };
+
+    if word.eq_ignore_ascii_case("normal") || word == "-1" {
+        return Some(None);
+    }
+    if word.eq_ignore_ascii_case("default") {
+        return Some(Some(Color::Default));
+    }
+    if let Some(index) = named(word) {
+        return Some(Some(NAMED[index]));
+    }
+    if let Some(index) = word
+        .get(..6)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("bright"))
+        .and_then(|_| named(&word[6..]))
+    {
+        return Some(Some(NAMED[index + 8]));
+    }
+    if let Some(index) = parse_u8(word) {
+        return Some(Some(Color::Ansi256(index)));
+    }
+    if let Some(hex) = word.strip_prefix('#') {
+        if hex.len() == 3 && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            let component = |i| u8::from_str_radix(&hex[i..=i], 16).ok().map(|c| c * 0x11);
+            return Some(Some(Color::Rgb(
+                component(0)?,
+                component(1)?,
+                component(2)?,
+            )));
+        }
+        return parse_rgb(word).map(|(r, g, b)| Some(Color::Rgb(r, g, b)));
+    }
+    None
+}
+
+/// Parses a single attribute word, setting it in `diff`, returns `false` if
+/// it is not a known attribute.
+fn attribute(diff: &mut StyleDiff, word: &str) -> bool {
+    let (value, name) = match word.get(..2) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("no") => {
+            let name = &word[2..];
+            (false, name.strip_prefix('-').unwrap_or(name))
+        }
+        _ => (true, word),
+    };
+
+    let is = |attribute: &str| name.eq_ignore_ascii_case(attribute);
+    if is("bold") {
+        diff.intensity = Some(if value {
+            Intensity::Bold
+        } else {
+            Intensity::Normal
+        });
+    } else if is("dim") {
+        diff.intensity = Some(if value {
+            Intensity::Faint
+        } else {
+            Intensity::Normal
+        });
+    } else if is("ul") {
+        diff.underline = Some(if value {
+            Underline::Single
+        } else {
+            Underline::None
+        });
+    } else if is("italic") {
+        diff.italic = Some(value);
+    } else if is("blink") {
+        diff.blink = Some(value);
+    } else if is("reverse") {
+        diff.reverse = Some(value);
+    } else if is("strike") {
+        diff.strikethrough = Some(value);
+    } else {
+        return false;
+    }
+    true
+}
+
+impl StyleDiff {
+    /// Parses a color in the syntax used by `git config`, such as
+    /// `bold red ul` or `#ff8800 black nobold`.
+    ///
+    /// The value is a whitespace separated list of words, the first color is
+    /// the foreground and the second is the background. Colors may be named
+    /// (`red`, `brightred`), `normal` to leave the color unchanged, `default`,
+    /// a palette index, or `#rrggbb`/`#rgb`. The attributes `bold`, `dim`,
+    /// `ul`, `blink`, `reverse`, `italic` and `strike` may be turned off with a
+    /// `no` or `no-` prefix, and `reset` resets every attribute before the
+    /// others are applied. Words are matched case-insensitively.
+    ///
+    /// ```rust
+    /// use stylish::{ParseStyleErrorKind, StyleDiff};
+    ///
+    /// let diff = StyleDiff::from_git_color("bold red ul")?;
+    /// assert_eq!(diff, "bold, fg=red, underline".parse()?);
+    ///
+    /// let diff = StyleDiff::from_git_color("normal #f80 no-italic")?;
+    /// assert_eq!(diff, "bg=#ff8800, italic=false".parse()?);
+    ///
+    /// let error = StyleDiff::from_git_color("red blod").unwrap_err();
+    /// assert_eq!(error.kind(), ParseStyleErrorKind::UnknownAttribute);
+    /// assert_eq!(error.span(), 4..8);
+    /// # Ok::<(), stylish::ParseStyleError>(())
+    /// ```
+    pub fn from_git_color(s: &str) -> Result<StyleDiff, ParseStyleError> {
+        let mut diff = StyleDiff::default();
+        let mut colors = 0;
+        let mut reset_all = false;
+
+        let words = s
+            .split(|c: char| c.is_ascii_whitespace())
+            .scan(0, |position, word| {
+                let start = *position;
+                *position += word.len() + 1;
+                Some((start, word))
+            })
+            .filter(|(_, word)| !word.is_empty());
+
+        for (offset, word) in words {
+            let span = offset..offset + word.len();
+            if let Some(color) = color(word) {
+                match colors {
+                    0 => diff.foreground = color,
+                    1 => diff.background = color,
+                    _ => {
+                        return Err(ParseStyleError::new(
+                            ParseStyleErrorKind::InvalidColor,
+                            span,
+                        ))
+                    }
+                }
+                colors += 1;
+            } else if word.eq_ignore_ascii_case("reset") {
+                reset_all = true;
+            } else if !attribute(&mut diff, word) {
+                return Err(ParseStyleError::new(
+                    ParseStyleErrorKind::UnknownAttribute,
+                    span,
+                ));
+            }
+        }
+
+        Ok(if reset_all { reset().merge(diff) } else { diff })
+    }
+}
@@ -10,6 +10,28 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod builder;
+mod cvd;
+mod depth;
+mod git;
+mod math;
+mod palette;
+mod parse;
+mod restyle;
+mod sgr;
+
+pub use crate::{
+    cvd::Deficiency,
+    depth::ColorDepth,
+    math::{Hsl, Hsv, MinContrast, Oklch},
+    palette::Palette,
+    parse::{ParseStyleError, ParseStyleErrorKind},
+    restyle::{Reset, RestyleFn, Swap, When},
+    sgr::SgrList,
+};
+#[doc(hidden)]
+pub use crate::{parse::parse_with_classes, sgr::parse_sgr};
+
 /// A color that can be used with [`Foreground`] to modify [`Style::foreground`]
 /// or [`Background`] to modify [`Style::background`].
 #[allow(dead_code)]
@@ -48,8 +70,32 @@ pub enum Color {
     BrightCyan,
     // Bright White
     BrightWhite,
+    /// A color from the 256-color indexed palette supported by most terminals.
+    ///
+    /// Indices `0..16` correspond to the basic and bright colors above,
+    /// `16..232` are a 6×6×6 color cube, and `232..256` are a grayscale ramp.
+    Ansi256(u8),
+    /// A 24-bit "truecolor" color, specified by its red, green and blue
+    /// components.
+    Rgb(u8, u8, u8),
     /// Default color
     Default,
+    /// Keep the color from the surrounding style.
+    ///
+    /// When used to restyle (e.g. with [`Foreground`] or in a [`StyleDiff`])
+    /// this leaves the existing color unchanged, unlike [`Color::Default`]
+    /// which resets it to the default. A [`Style`] that still contains it when
+    /// rendered has no surrounding style to inherit from, so it is rendered
+    /// as [`Color::Default`].
+    ///
+    /// ```rust
+    /// use stylish::{Color, Foreground, Style};
+    ///
+    /// let red = Style::default().with(Foreground(Color::Red));
+    /// assert_eq!(red.with(Foreground(Color::Inherit)), red);
+    /// assert_eq!(red.with(Foreground(Color::Default)), Style::default());
+    /// ```
+    Inherit,
 }
 
 /// An intensity to render text with, to emphasise or de-emphasise it as needed.
@@ -64,9 +110,35 @@ pub enum Intensity {
     Faint,
 }
 
+/// A line to draw under text.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Underline {
+    /// No underline
+    None,
+    /// A single straight underline
+    Single,
+    /// A double straight underline
+    Double,
+    /// A single wavy underline, commonly used to mark diagnostics
+    Curly,
+    /// A single dotted underline
+    Dotted,
+    /// A single dashed underline
+    Dashed,
+}
+
 /// A style to render text with, setting the foreground and background colors,
-/// along with intensity.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+/// along with intensity and other text attributes.
+///
+/// With the `serde` feature enabled this is serialized in the same form as the
+/// [`StyleDiff`] from [`Style::default`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "StyleDiff", into = "StyleDiff")
+)]
 #[non_exhaustive]
 pub struct Style {
     /// The text foreground color
@@ -75,21 +147,86 @@ pub struct Style {
     pub background: Color,
     /// The text intensity
     pub intensity: Intensity,
+    /// Whether the text is italicized
+    pub italic: bool,
+    /// The line drawn under the text
+    pub underline: Underline,
+    /// The color of the line drawn under the text, [`Color::Default`] uses the
+    /// foreground color
+    pub underline_color: Color,
+    /// Whether the text has a line through it
+    pub strikethrough: bool,
+    /// Whether the text has the foreground and background colors swapped
+    pub reverse: bool,
+    /// Whether the text blinks
+    pub blink: bool,
+    /// Whether the text is hidden (while still taking up space)
+    pub hidden: bool,
+    /// Whether the text has a line over it
+    pub overline: bool,
 }
 
 /// A diff between two styles.
 ///
 /// Most useful for some implementors of `stylish::Write` to detect changes
 /// between two parts, or for applying multiple changes to a style at once.
+///
+/// With the `serde` feature enabled this is serialized as a map containing
+/// only the changed fields, with colors and other values as the strings used
+/// in the attribute syntax.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use stylish::{Color, Intensity, StyleDiff};
+///
+/// let diff: StyleDiff = serde_json::from_str(r##"{ "foreground": "#ff8800", "intensity": "bold" }"##)?;
+/// assert_eq!(diff.foreground, Some(Color::Rgb(0xff, 0x88, 0x00)));
+/// assert_eq!(diff.intensity, Some(Intensity::Bold));
+/// assert_eq!(serde_json::to_string(&diff)?, r##"{"foreground":"#ff8800","intensity":"bold"}"##);
+/// # }
+/// # Ok::<(), serde_json::Error>(())
+/// ```
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
 #[non_exhaustive]
 pub struct StyleDiff {
     /// The change in the text foreground color
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub foreground: Option<Color>,
     /// The change in the text background color
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub background: Option<Color>,
     /// The change in the text intensity
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub intensity: Option<Intensity>,
+    /// The change in whether the text is italicized
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub italic: Option<bool>,
+    /// The change in the line drawn under the text
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub underline: Option<Underline>,
+    /// The change in the color of the line drawn under the text
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub underline_color: Option<Color>,
+    /// The change in whether the text has a line through it
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub strikethrough: Option<bool>,
+    /// The change in whether the text has its colors swapped
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub reverse: Option<bool>,
+    /// The change in whether the text blinks
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub blink: Option<bool>,
+    /// The change in whether the text is hidden
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub hidden: Option<bool>,
+    /// The change in whether the text has a line over it
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub overline: Option<bool>,
 }
 
 /// A [`Restyle`] implementor for setting [`Style::foreground`].
@@ -118,6 +255,103 @@ pub struct Foreground(pub Color);
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub struct Background(pub Color);
 
+/// A [`Restyle`] implementor for setting [`Style::underline_color`].
+///
+/// ```rust
+/// use stylish::{Color, Style, Underline, UnderlineColor};
+///
+/// let mut expected = Style::default();
+/// expected.underline = Underline::Curly;
+/// expected.underline_color = Color::Red;
+///
+/// assert_eq!(
+///     Style::default()
+///         .with(Underline::Curly)
+///         .with(UnderlineColor(Color::Red)),
+///     expected
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct UnderlineColor(pub Color);
+
+/// A [`Restyle`] implementor for setting [`Style::italic`].
+///
+/// ```rust
+/// use stylish::{Italic, Style};
+///
+/// let mut expected = Style::default();
+/// expected.italic = true;
+///
+/// assert_eq!(Style::default().with(Italic(true)), expected);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Italic(pub bool);
+
+/// A [`Restyle`] implementor for setting [`Style::strikethrough`].
+///
+/// ```rust
+/// use stylish::{Strikethrough, Style};
+///
+/// let mut expected = Style::default();
+/// expected.strikethrough = true;
+///
+/// assert_eq!(Style::default().with(Strikethrough(true)), expected);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Strikethrough(pub bool);
+
+/// A [`Restyle`] implementor for setting [`Style::reverse`].
+///
+/// ```rust
+/// use stylish::{Reverse, Style};
+///
+/// let mut expected = Style::default();
+/// expected.reverse = true;
+///
+/// assert_eq!(Style::default().with(Reverse(true)), expected);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Reverse(pub bool);
+
+/// A [`Restyle`] implementor for setting [`Style::blink`].
+///
+/// ```rust
+/// use stylish::{Blink, Style};
+///
+/// let mut expected = Style::default();
+/// expected.blink = true;
+///
+/// assert_eq!(Style::default().with(Blink(true)), expected);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Blink(pub bool);
+
+/// A [`Restyle`] implementor for setting [`Style::hidden`].
+///
+/// ```rust
+/// use stylish::{Hidden, Style};
+///
+/// let mut expected = Style::default();
+/// expected.hidden = true;
+///
+/// assert_eq!(Style::default().with(Hidden(true)), expected);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Hidden(pub bool);
+
+/// A [`Restyle`] implementor for setting [`Style::overline`].
+///
+/// ```rust
+/// use stylish::{Overline, Style};
+///
+/// let mut expected = Style::default();
+/// expected.overline = true;
+///
+/// assert_eq!(Style::default().with(Overline(true)), expected);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Overline(pub bool);
+
 /// A trait for modifications to [`Style`], allowing an ergonomic API with
 /// [`Style::with`] and `stylish::Formatter::with`.
 ///
@@ -158,6 +392,31 @@ impl Default for Intensity {
     }
 }
 
+impl Default for Underline {
+    #[inline]
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+impl Default for Style {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Color {
+    /// Resolve [`Color::Inherit`] to the `parent` color.
+    #[inline]
+    fn or_inherit(self, parent: Color) -> Color {
+        match self {
+            Color::Inherit => parent,
+            color => color,
+        }
+    }
+}
+
 impl Style {
     /// Apply a modification to this style, returning the result.
     ///
@@ -192,6 +451,7 @@ impl Style {
     ///         foreground: Some(Color::Cyan),
     ///         background: None,
     ///         intensity: None,
+    ///         italic: None,
     ///         ..
     ///     }
     /// ));
@@ -206,10 +466,135 @@ impl Style {
             }
         }
 
+        let original = original.inherit_from(Style::default());
+        let this = self.inherit_from(Style::default());
+
         StyleDiff {
-            foreground: diff(original.foreground, self.foreground),
-            background: diff(original.background, self.background),
-            intensity: diff(original.intensity, self.intensity),
+            foreground: diff(original.foreground, this.foreground),
+            background: diff(original.background, this.background),
+            intensity: diff(original.intensity, this.intensity),
+            italic: diff(original.italic, this.italic),
+            underline: diff(original.underline, this.underline),
+            underline_color: diff(original.underline_color, this.underline_color),
+            strikethrough: diff(original.strikethrough, this.strikethrough),
+            reverse: diff(original.reverse, this.reverse),
+            blink: diff(original.blink, this.blink),
+            hidden: diff(original.hidden, this.hidden),
+            overline: diff(original.overline, this.overline),
+        }
+    }
+
+    /// Replace any [`Color::Inherit`] in this style with the matching color
+    /// from `parent`.
+    ///
+    /// Writers should resolve styles against [`Style::default`] before
+    /// comparing them, so that an inherited color is treated the same as the
+    /// default color it renders as.
+    ///
+    /// ```rust
+    /// use stylish::{Background, Color, Foreground, Style};
+    ///
+    /// let parent = Style::default().with(Foreground(Color::Red));
+    /// let style = Style::default()
+    ///     .with(Foreground(Color::Blue))
+    ///     .with(Background(Color::Green));
+    /// let mut inheriting = style;
+    /// inheriting.background = Color::Inherit;
+    ///
+    /// assert_eq!(
+    ///     inheriting.inherit_from(parent),
+    ///     style.with(Background(Color::Default))
+    /// );
+    /// assert_eq!(
+    ///     Style::default().inherit_from(parent),
+    ///     Style::default()
+    /// );
+    /// ```
+    #[inline]
+    pub fn inherit_from(self, parent: Style) -> Style {
+        Style {
+            foreground: self.foreground.or_inherit(parent.foreground),
+            background: self.background.or_inherit(parent.background),
+            underline_color: self.underline_color.or_inherit(parent.underline_color),
+            ..self
+        }
+    }
+}
+
+impl StyleDiff {
+    /// Combine this diff with `other`, producing a diff that makes the same
+    /// changes as applying this diff then `other`.
+    ///
+    /// ```rust
+    /// use stylish::StyleDiff;
+    ///
+    /// let diff: StyleDiff = "fg=red, bold".parse()?;
+    /// let merged = diff.merge("fg=blue, italic".parse()?);
+    ///
+    /// assert_eq!(merged, "fg=blue, bold, italic".parse()?);
+    /// # Ok::<(), stylish::ParseStyleError>(())
+    /// ```
+    #[inline]
+    pub fn merge(self, other: StyleDiff) -> StyleDiff {
+        fn color(first: Option<Color>, second: Option<Color>) -> Option<Color> {
+            match second {
+                None | Some(Color::Inherit) => first.or(second),
+                second => second,
+            }
+        }
+
+        StyleDiff {
+            foreground: color(self.foreground, other.foreground),
+            background: color(self.background, other.background),
+            intensity: other.intensity.or(self.intensity),
+            italic: other.italic.or(self.italic),
+            underline: other.underline.or(self.underline),
+            underline_color: color(self.underline_color, other.underline_color),
+            strikethrough: other.strikethrough.or(self.strikethrough),
+            reverse: other.reverse.or(self.reverse),
+            blink: other.blink.or(self.blink),
+            hidden: other.hidden.or(self.hidden),
+            overline: other.overline.or(self.overline),
+        }
+    }
+
+    /// Find the diff that undoes the changes this diff makes when applied to
+    /// `original`, restoring each attribute it changes to the value it had in
+    /// `original`.
+    ///
+    /// ```rust
+    /// use stylish::{Color, Foreground, Style, StyleDiff};
+    ///
+    /// let original = Style::default().with(Foreground(Color::Green));
+    /// let diff: StyleDiff = "fg=red, bold".parse()?;
+    ///
+    /// let inverse = diff.inverse(original);
+    /// assert_eq!(inverse, "fg=green, normal".parse()?);
+    /// assert_eq!(original.with(diff).with(inverse), original);
+    /// # Ok::<(), stylish::ParseStyleError>(())
+    /// ```
+    #[inline]
+    pub fn inverse(self, original: Style) -> StyleDiff {
+        fn restore<T>(changed: Option<T>, original: T) -> Option<T> {
+            changed.map(|_| original)
+        }
+
+        fn color(changed: Option<Color>, original: Color) -> Option<Color> {
+            restore(changed.filter(|&color| color != Color::Inherit), original)
+        }
+
+        StyleDiff {
+            foreground: color(self.foreground, original.foreground),
+            background: color(self.background, original.background),
+            intensity: restore(self.intensity, original.intensity),
+            italic: restore(self.italic, original.italic),
+            underline: restore(self.underline, original.underline),
+            underline_color: color(self.underline_color, original.underline_color),
+            strikethrough: restore(self.strikethrough, original.strikethrough),
+            reverse: restore(self.reverse, original.reverse),
+            blink: restore(self.blink, original.blink),
+            hidden: restore(self.hidden, original.hidden),
+            overline: restore(self.overline, original.overline),
         }
     }
 }
@@ -242,22 +627,49 @@ impl<T: Restyle> Restyle for Option<T> {
     }
 }
 
+impl From<StyleDiff> for Style {
+    #[inline]
+    fn from(diff: StyleDiff) -> Self {
+        Style::default().with(diff)
+    }
+}
+
+impl From<Style> for StyleDiff {
+    #[inline]
+    fn from(style: Style) -> Self {
+        style.diff_from(Style::default())
+    }
+}
+
 impl Restyle for StyleDiff {
     #[inline]
     fn apply(&self, style: Style) -> Style {
-        (
-            self.foreground.map(Foreground),
-            self.background.map(Background),
-            self.intensity,
-        )
-            .apply(style)
+        Style {
+            foreground: self
+                .foreground
+                .map_or(style.foreground, |c| c.or_inherit(style.foreground)),
+            background: self
+                .background
+                .map_or(style.background, |c| c.or_inherit(style.background)),
+            intensity: self.intensity.unwrap_or(style.intensity),
+            italic: self.italic.unwrap_or(style.italic),
+            underline: self.underline.unwrap_or(style.underline),
+            underline_color: self.underline_color.map_or(style.underline_color, |c| {
+                c.or_inherit(style.underline_color)
+            }),
+            strikethrough: self.strikethrough.unwrap_or(style.strikethrough),
+            reverse: self.reverse.unwrap_or(style.reverse),
+            blink: self.blink.unwrap_or(style.blink),
+            hidden: self.hidden.unwrap_or(style.hidden),
+            overline: self.overline.unwrap_or(style.overline),
+        }
     }
 }
 
 impl Restyle for Style {
     #[inline]
-    fn apply(&self, _style: Style) -> Style {
-        *self
+    fn apply(&self, style: Style) -> Style {
+        self.inherit_from(style)
     }
 }
 
@@ -266,7 +678,7 @@ impl Restyle for Foreground {
     fn apply(&self, style: Style) -> Style {
         let &Foreground(foreground) = self;
         Style {
-            foreground,
+            foreground: foreground.or_inherit(style.foreground),
             ..style
         }
     }
@@ -277,7 +689,18 @@ impl Restyle for Background {
     fn apply(&self, style: Style) -> Style {
         let &Background(background) = self;
         Style {
-            background,
+            background: background.or_inherit(style.background),
+            ..style
+        }
+    }
+}
+
+impl Restyle for UnderlineColor {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        let &UnderlineColor(underline_color) = self;
+        Style {
+            underline_color: underline_color.or_inherit(style.underline_color),
             ..style
         }
     }
@@ -293,21 +716,95 @@ impl Restyle for Intensity {
     }
 }
 
-impl Restyle for () {
+impl Restyle for Italic {
     #[inline]
     fn apply(&self, style: Style) -> Style {
-        style
+        let &Italic(italic) = self;
+        Style { italic, ..style }
+    }
+}
+
+impl Restyle for Strikethrough {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        let &Strikethrough(strikethrough) = self;
+        Style {
+            strikethrough,
+            ..style
+        }
+    }
+}
+
+impl Restyle for Reverse {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        let &Reverse(reverse) = self;
+        Style { reverse, ..style }
+    }
+}
+
+impl Restyle for Blink {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        let &Blink(blink) = self;
+        Style { blink, ..style }
+    }
+}
+
+impl Restyle for Hidden {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        let &Hidden(hidden) = self;
+        Style { hidden, ..style }
+    }
+}
+
+impl Restyle for Overline {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        let &Overline(overline) = self;
+        Style { overline, ..style }
     }
 }
 
-impl<T: Restyle, U: Restyle> Restyle for (T, U) {
+impl Restyle for Underline {
+    #[inline]
     fn apply(&self, style: Style) -> Style {
-        style.with(&self.0).with(&self.1)
+        Style {
+            underline: *self,
+            ..style
+        }
     }
 }
 
-impl<T: Restyle, U: Restyle, V: Restyle> Restyle for (T, U, V) {
+impl Restyle for () {
+    #[inline]
     fn apply(&self, style: Style) -> Style {
-        style.with(&self.0).with(&self.1).with(&self.2)
+        style
     }
 }
+
+macro_rules! impl_tuple {
+    ($($name:ident)+) => {
+        impl<$($name: Restyle),+> Restyle for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn apply(&self, style: Style) -> Style {
+                let ($($name,)+) = self;
+                style$(.with($name))+
+            }
+        }
+    };
+}
+
+impl_tuple!(A);
+impl_tuple!(A B);
+impl_tuple!(A B C);
+impl_tuple!(A B C D);
+impl_tuple!(A B C D E);
+impl_tuple!(A B C D E F);
+impl_tuple!(A B C D E F G);
+impl_tuple!(A B C D E F G H);
+impl_tuple!(A B C D E F G H I);
+impl_tuple!(A B C D E F G H I J);
+impl_tuple!(A B C D E F G H I J K);
+impl_tuple!(A B C D E F G H I J K L);
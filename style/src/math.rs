@@ -0,0 +1,474 @@
+use crate::{Color, Palette, Restyle, Style};
+
+/// A color in the HSL (hue, saturation, lightness) color space, convertible to
+/// and from [`Color`].
+///
+/// ```rust
+/// use stylish::{Color, Hsl};
+///
+/// let hsl = Color::Rgb(0xff, 0x80, 0x00).to_hsl().unwrap();
+/// assert_eq!(hsl.hue.round(), 30.0);
+/// assert_eq!(Color::from(Hsl { lightness: 0.25, ..hsl }), Color::Rgb(0x80, 0x40, 0x00));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Hsl {
+    /// The hue in degrees, from `0.0` to `360.0`
+    pub hue: f32,
+    /// The saturation, from `0.0` to `1.0`
+    pub saturation: f32,
+    /// The lightness, from `0.0` (black) to `1.0` (white)
+    pub lightness: f32,
+}
+
+/// A color in the HSV (hue, saturation, value) color space, convertible to and
+/// from [`Color`].
+///
+/// ```rust
+/// use stylish::{Color, Hsv};
+///
+/// let hsv = Hsv { hue: 120.0, saturation: 1.0, value: 0.5 };
+/// assert_eq!(Color::from(hsv), Color::Rgb(0x00, 0x80, 0x00));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Hsv {
+    /// The hue in degrees, from `0.0` to `360.0`
+    pub hue: f32,
+    /// The saturation, from `0.0` to `1.0`
+    pub saturation: f32,
+    /// The value, from `0.0` (black) to `1.0` (full brightness)
+    pub value: f32,
+}
+
+/// A color in the perceptually uniform [OKLCH] color space, convertible to and
+/// from [`Color`].
+///
+/// Converting to [`Color`] clamps colors outside the sRGB gamut.
+///
+/// ```rust
+/// use stylish::{Color, Oklch};
+///
+/// let oklch = Color::Rgb(0xff, 0xff, 0xff).to_oklch().unwrap();
+/// assert!((oklch.lightness - 1.0).abs() < 0.001);
+/// assert!(oklch.chroma < 0.001);
+/// assert_eq!(Color::from(Oklch { lightness: 0.0, chroma: 0.0, hue: 0.0 }), Color::Rgb(0, 0, 0));
+/// ```
+///
+/// [OKLCH]: https://bottosson.github.io/posts/oklab/
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Oklch {
+    /// The perceived lightness, from `0.0` (black) to `1.0` (white)
+    pub lightness: f32,
+    /// The chroma, from `0.0` (gray) to around `0.37` for the most saturated
+    /// sRGB colors
+    pub chroma: f32,
+    /// The hue in degrees, from `0.0` to `360.0`
+    pub hue: f32,
+}
+
+/// A [`Restyle`] implementor that adjusts [`Style::foreground`] to have at least
+/// the given [WCAG contrast ratio] against [`Style::background`], by changing
+/// its lightness or, failing that, replacing it with
+/// [`Color::readable_foreground`].
+///
+/// Colors without a known RGB value (such as [`Color::Default`]) are left
+/// unchanged.
+///
+/// ```rust
+/// use stylish::{Background, Color, Foreground, MinContrast, Style};
+///
+/// let style = Style::default()
+///     .with(Foreground(Color::Rgb(0x40, 0x40, 0x80)))
+///     .with(Background(Color::Rgb(0x20, 0x20, 0x20)));
+/// assert!(style.foreground.contrast_ratio(style.background).unwrap() < 4.5);
+///
+/// let adjusted = style.with(MinContrast(4.5));
+/// assert!(adjusted.foreground.contrast_ratio(adjusted.background).unwrap() >= 4.5);
+/// ```
+///
+/// [WCAG contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct MinContrast(pub f32);
+
+const BLACK: Color = Color::Rgb(0x00, 0x00, 0x00);
+const WHITE: Color = Color::Rgb(0xff, 0xff, 0xff);
+
+pub(crate) fn to_unit(channel: u8) -> f32 {
+    f32::from(channel) / 255.0
+}
+
+pub(crate) fn from_unit(channel: f32) -> u8 {
+    // The clamp ensures the cast cannot truncate
+    libm::roundf(channel.clamp(0.0, 1.0) * 255.0) as u8
+}
+
+/// Convert a gamma-encoded sRGB channel to linear light.
+pub(crate) fn to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        libm::powf((channel + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Convert a linear light channel to gamma-encoded sRGB.
+pub(crate) fn from_linear(channel: f32) -> f32 {
+    if channel <= 0.0031308 {
+        channel * 12.92
+    } else {
+        1.055 * libm::powf(channel, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// Normalize a hue in degrees to `0.0..360.0`.
+fn wrap_hue(hue: f32) -> f32 {
+    let hue = hue % 360.0;
+    if hue < 0.0 {
+        hue + 360.0
+    } else {
+        hue
+    }
+}
+
+/// The hue in degrees and the chroma (`max - min`) of an RGB color.
+fn hue_chroma(r: f32, g: f32, b: f32) -> (f32, f32) {
+    let max = r.max(g).max(b);
+    let chroma = max - r.min(g).min(b);
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / chroma)
+    } else if max == g {
+        60.0 * ((b - r) / chroma + 2.0)
+    } else {
+        60.0 * ((r - g) / chroma + 4.0)
+    };
+    (wrap_hue(hue), chroma)
+}
+
+/// The RGB color for a hue and chroma, offset by `min`.
+fn from_hue_chroma(hue: f32, chroma: f32, min: f32) -> Color {
+    let hue = wrap_hue(hue) / 60.0;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Color::Rgb(from_unit(r + min), from_unit(g + min), from_unit(b + min))
+}
+
+/// Convert a color in the Oklab color space to RGB, clamping colors outside
+/// the sRGB gamut.
+fn from_oklab(lightness: f32, a: f32, b: f32) -> Color {
+    let l = lightness + 0.396_337_78 * a + 0.215_803_76 * b;
+    let m = lightness - 0.105_561_346 * a - 0.063_854_17 * b;
+    let s = lightness - 0.089_484_18 * a - 1.291_485_5 * b;
+    let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+    let r = 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s;
+    let g = -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s;
+    let b = -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s;
+
+    let channel = |c: f32| from_unit(from_linear(c.clamp(0.0, 1.0)));
+    Color::Rgb(channel(r), channel(g), channel(b))
+}
+
+impl Color {
+    /// The RGB value of this color as fractions, using the xterm palette for
+    /// named and 256-color palette colors.
+    fn to_unit_rgb(self) -> Option<(f32, f32, f32)> {
+        let (r, g, b) = Palette::XTERM.rgb(self)?;
+        Some((to_unit(r), to_unit(g), to_unit(b)))
+    }
+
+    /// Convert this color to [`Hsl`], returns [`None`] for colors without a
+    /// known RGB value (such as [`Color::Default`]).
+    ///
+    /// Named and 256-color palette colors use the xterm RGB values, as do all
+    /// the other color operations.
+    pub fn to_hsl(self) -> Option<Hsl> {
+        let (r, g, b) = self.to_unit_rgb()?;
+        let (hue, chroma) = hue_chroma(r, g, b);
+        let lightness = (r.max(g).max(b) + r.min(g).min(b)) / 2.0;
+        let saturation = if lightness <= 0.0 || lightness >= 1.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        Some(Hsl {
+            hue,
+            saturation,
+            lightness,
+        })
+    }
+
+    /// Convert this color to [`Hsv`], returns [`None`] for colors without a
+    /// known RGB value (such as [`Color::Default`]).
+    pub fn to_hsv(self) -> Option<Hsv> {
+        let (r, g, b) = self.to_unit_rgb()?;
+        let (hue, chroma) = hue_chroma(r, g, b);
+        let value = r.max(g).max(b);
+        let saturation = if value == 0.0 { 0.0 } else { chroma / value };
+        Some(Hsv {
+            hue,
+            saturation,
+            value,
+        })
+    }
+
+    /// The Oklab lightness and `a`/`b` components of this color.
+    fn to_oklab(self) -> Option<(f32, f32, f32)> {
+        let (r, g, b) = self.to_unit_rgb()?;
+        let (r, g, b) = (to_linear(r), to_linear(g), to_linear(b));
+
+        let l = libm::cbrtf(0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b);
+        let m = libm::cbrtf(0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b);
+        let s = libm::cbrtf(0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b);
+
+        Some((
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        ))
+    }
+
+    /// Convert this color to [`Oklch`], returns [`None`] for colors without a
+    /// known RGB value (such as [`Color::Default`]).
+    pub fn to_oklch(self) -> Option<Oklch> {
+        let (lightness, a, b) = self.to_oklab()?;
+        Some(Oklch {
+            lightness,
+            chroma: libm::hypotf(a, b),
+            hue: wrap_hue(libm::atan2f(b, a).to_degrees()),
+        })
+    }
+
+    /// Lighten this color by increasing its [`Hsl::lightness`] by `amount`
+    /// (clamped to `0.0..=1.0`), colors without a known RGB value are returned
+    /// unchanged.
+    ///
+    /// ```rust
+    /// use stylish::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0x80, 0x00, 0x00).lighten(0.25), Color::Rgb(0xff, 0x00, 0x00));
+    /// assert_eq!(Color::Rgb(0x80, 0x00, 0x00).darken(0.5), Color::Rgb(0x00, 0x00, 0x00));
+    /// assert_eq!(Color::Default.lighten(0.25), Color::Default);
+    /// ```
+    pub fn lighten(self, amount: f32) -> Color {
+        self.to_hsl().map_or(self, |hsl| {
+            Color::from(Hsl {
+                lightness: (hsl.lightness + amount).clamp(0.0, 1.0),
+                ..hsl
+            })
+        })
+    }
+
+    /// Darken this color by decreasing its [`Hsl::lightness`] by `amount`, see
+    /// [`Color::lighten`].
+    pub fn darken(self, amount: f32) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Saturate this color by increasing its [`Hsl::saturation`] by `amount`
+    /// (clamped to `0.0..=1.0`), colors without a known RGB value are returned
+    /// unchanged.
+    ///
+    /// ```rust
+    /// use stylish::Color;
+    ///
+    /// assert_eq!(Color::Rgb(0x60, 0x40, 0x40).saturate(1.0), Color::Rgb(0xa0, 0x00, 0x00));
+    /// assert_eq!(Color::Rgb(0x60, 0x40, 0x40).desaturate(1.0), Color::Rgb(0x50, 0x50, 0x50));
+    /// ```
+    pub fn saturate(self, amount: f32) -> Color {
+        self.to_hsl().map_or(self, |hsl| {
+            Color::from(Hsl {
+                saturation: (hsl.saturation + amount).clamp(0.0, 1.0),
+                ..hsl
+            })
+        })
+    }
+
+    /// Desaturate this color by decreasing its [`Hsl::saturation`] by
+    /// `amount`, see [`Color::saturate`].
+    pub fn desaturate(self, amount: f32) -> Color {
+        self.saturate(-amount)
+    }
+
+    /// Mix this color with `other`, `ratio` is the fraction of `other` in the
+    /// result from `0.0` (only this color) to `1.0` (only `other`).
+    ///
+    /// Colors are mixed in linear light so the result has the expected
+    /// brightness. If either color has no known RGB value this color is
+    /// returned unchanged.
+    ///
+    /// ```rust
+    /// use stylish::Color;
+    ///
+    /// let red = Color::Rgb(0xff, 0x00, 0x00);
+    /// let blue = Color::Rgb(0x00, 0x00, 0xff);
+    /// assert_eq!(red.mix(blue, 0.0), red);
+    /// assert_eq!(red.mix(blue, 0.5), Color::Rgb(0xbc, 0x00, 0xbc));
+    /// assert_eq!(red.mix(Color::Default, 0.5), red);
+    /// ```
+    pub fn mix(self, other: Color, ratio: f32) -> Color {
+        let (Some((r1, g1, b1)), Some((r2, g2, b2))) = (self.to_unit_rgb(), other.to_unit_rgb())
+        else {
+            return self;
+        };
+        let ratio = ratio.clamp(0.0, 1.0);
+        let mix = |a: f32, b: f32| {
+            from_unit(from_linear(
+                to_linear(a) * (1.0 - ratio) + to_linear(b) * ratio,
+            ))
+        };
+        Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+    }
+
+    /// Interpolate between this color and `other` in the perceptually uniform
+    /// [Oklab] color space, `ratio` is from `0.0` (only this color) to `1.0`
+    /// (only `other`).
+    ///
+    /// Unlike [`Color::mix`] the intermediate colors change evenly in
+    /// perceived lightness and hue, which suits gradients. If either color has
+    /// no known RGB value this color is returned unchanged.
+    ///
+    /// ```rust
+    /// use stylish::Color;
+    ///
+    /// let red = Color::Rgb(0xff, 0x00, 0x00);
+    /// let blue = Color::Rgb(0x00, 0x00, 0xff);
+    /// assert_eq!(red.interpolate(blue, 0.0), red);
+    /// assert_eq!(red.interpolate(blue, 1.0), blue);
+    /// assert_eq!(red.interpolate(blue, 0.5), Color::Rgb(0x8c, 0x53, 0xa2));
+    /// assert_eq!(red.interpolate(Color::Default, 0.5), red);
+    /// ```
+    ///
+    /// [Oklab]: https://bottosson.github.io/posts/oklab/
+    pub fn interpolate(self, other: Color, ratio: f32) -> Color {
+        let (Some((l1, a1, b1)), Some((l2, a2, b2))) = (self.to_oklab(), other.to_oklab()) else {
+            return self;
+        };
+        let ratio = ratio.clamp(0.0, 1.0);
+        let lerp = |x: f32, y: f32| x + (y - x) * ratio;
+        from_oklab(lerp(l1, l2), lerp(a1, a2), lerp(b1, b2))
+    }
+
+    /// The [WCAG relative luminance] of this color, from `0.0` (black) to
+    /// `1.0` (white), returns [`None`] for colors without a known RGB value.
+    ///
+    /// [WCAG relative luminance]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    pub fn relative_luminance(self) -> Option<f32> {
+        let (r, g, b) = self.to_unit_rgb()?;
+        Some(0.2126 * to_linear(r) + 0.7152 * to_linear(g) + 0.0722 * to_linear(b))
+    }
+
+    /// The [WCAG contrast ratio] between this color and `other`, from `1.0`
+    /// (no contrast) to `21.0` (black and white), returns [`None`] if either
+    /// color has no known RGB value.
+    ///
+    /// ```rust
+    /// use stylish::Color;
+    ///
+    /// let black = Color::Rgb(0x00, 0x00, 0x00);
+    /// let white = Color::Rgb(0xff, 0xff, 0xff);
+    /// assert!((black.contrast_ratio(white).unwrap() - 21.0).abs() < 0.001);
+    /// assert_eq!(white.contrast_ratio(white), Some(1.0));
+    /// assert_eq!(white.contrast_ratio(Color::Default), None);
+    /// ```
+    ///
+    /// [WCAG contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    pub fn contrast_ratio(self, other: Color) -> Option<f32> {
+        let a = self.relative_luminance()?;
+        let b = other.relative_luminance()?;
+        Some((a.max(b) + 0.05) / (a.min(b) + 0.05))
+    }
+
+    /// Pick black or white, whichever has the higher contrast against this
+    /// color when used as a background, returns [`Color::Default`] if this
+    /// color has no known RGB value.
+    ///
+    /// ```rust
+    /// use stylish::Color;
+    ///
+    /// assert_eq!(Color::Yellow.readable_foreground(), Color::Rgb(0x00, 0x00, 0x00));
+    /// assert_eq!(Color::Blue.readable_foreground(), Color::Rgb(0xff, 0xff, 0xff));
+    /// ```
+    pub fn readable_foreground(self) -> Color {
+        match (BLACK.contrast_ratio(self), WHITE.contrast_ratio(self)) {
+            (Some(black), Some(white)) if black >= white => BLACK,
+            (Some(_), Some(_)) => WHITE,
+            _ => Color::Default,
+        }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        let lightness = hsl.lightness.clamp(0.0, 1.0);
+        let saturation = hsl.saturation.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        from_hue_chroma(hsl.hue, chroma, lightness - chroma / 2.0)
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Self {
+        let value = hsv.value.clamp(0.0, 1.0);
+        let chroma = value * hsv.saturation.clamp(0.0, 1.0);
+        from_hue_chroma(hsv.hue, chroma, value - chroma)
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(oklch: Oklch) -> Self {
+        let hue = oklch.hue.to_radians();
+        from_oklab(
+            oklch.lightness,
+            oklch.chroma * libm::cosf(hue),
+            oklch.chroma * libm::sinf(hue),
+        )
+    }
+}
+
+impl Restyle for MinContrast {
+    fn apply(&self, style: Style) -> Style {
+        let &MinContrast(min) = self;
+        let (foreground, background) = (style.foreground, style.background);
+        if foreground
+            .contrast_ratio(background)
+            .is_none_or(|ratio| ratio >= min)
+        {
+            return style;
+        }
+
+        // Move the lightness towards whichever of black or white contrasts more
+        // with the background, keeping the hue and chroma where possible.
+        let mut oklch = foreground.to_oklch().unwrap();
+        let step = if background.readable_foreground() == WHITE {
+            0.02
+        } else {
+            -0.02
+        };
+        while (0.0..=1.0).contains(&oklch.lightness) {
+            oklch.lightness += step;
+            let adjusted = Color::from(oklch);
+            if adjusted
+                .contrast_ratio(background)
+                .is_some_and(|ratio| ratio >= min)
+            {
+                return Style {
+                    foreground: adjusted,
+                    ..style
+                };
+            }
+        }
+
+        Style {
+            foreground: background.readable_foreground(),
+            ..style
+        }
+    }
+}
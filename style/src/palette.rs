@@ -0,0 +1,166 @@
+use crate::{Color, Restyle, Style};
+
+/// The RGB values used to display the basic and bright [`Color`]s, for output
+/// formats (like HTML) that need exact colors rather than deferring to a
+/// terminal's configuration.
+///
+/// ```rust
+/// use stylish::{Color, Palette};
+///
+/// let mut palette = Palette::XTERM;
+/// palette.colors[1] = (0xab, 0x46, 0x42);
+///
+/// assert_eq!(palette.rgb(Color::Red), Some((0xab, 0x46, 0x42)));
+/// assert_eq!(palette.rgb(Color::Ansi256(1)), Some((0xab, 0x46, 0x42)));
+/// assert_eq!(palette.rgb(Color::Ansi256(196)), Some((0xff, 0x00, 0x00)));
+/// assert_eq!(palette.rgb(Color::Rgb(1, 2, 3)), Some((1, 2, 3)));
+/// assert_eq!(palette.rgb(Color::Default), None);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Palette {
+    /// The RGB values of [`Color::Black`] through [`Color::White`] followed by
+    /// [`Color::BrightBlack`] through [`Color::BrightWhite`], the same order
+    /// as the first 16 entries of the 256-color palette.
+    pub colors: [(u8, u8, u8); 16],
+}
+
+impl Palette {
+    /// The colors used by xterm by default.
+    pub const XTERM: Palette = Palette {
+        colors: [
+            (0x00, 0x00, 0x00),
+            (0xcd, 0x00, 0x00),
+            (0x00, 0xcd, 0x00),
+            (0xcd, 0xcd, 0x00),
+            (0x00, 0x00, 0xee),
+            (0xcd, 0x00, 0xcd),
+            (0x00, 0xcd, 0xcd),
+            (0xe5, 0xe5, 0xe5),
+            (0x7f, 0x7f, 0x7f),
+            (0xff, 0x00, 0x00),
+            (0x00, 0xff, 0x00),
+            (0xff, 0xff, 0x00),
+            (0x5c, 0x5c, 0xff),
+            (0xff, 0x00, 0xff),
+            (0x00, 0xff, 0xff),
+            (0xff, 0xff, 0xff),
+        ],
+    };
+
+    /// A palette based on the [Okabe-Ito] colors, which remain distinguishable
+    /// for people with the common forms of color vision deficiency (red and
+    /// green become vermillion and bluish green). The bright colors are
+    /// lighter versions of the basic colors.
+    ///
+    /// Used as a [`Restyle`] implementor this replaces the named colors in a
+    /// style with these RGB values.
+    ///
+    /// [Okabe-Ito]: https://jfly.uni-koeln.de/color/
+    pub const OKABE_ITO: Palette = Palette {
+        colors: [
+            (0x00, 0x00, 0x00),
+            (0xd5, 0x5e, 0x00),
+            (0x00, 0x9e, 0x73),
+            (0xf0, 0xe4, 0x42),
+            (0x00, 0x72, 0xb2),
+            (0xcc, 0x79, 0xa7),
+            (0x56, 0xb4, 0xe9),
+            (0xe0, 0xe0, 0xe0),
+            (0x80, 0x80, 0x80),
+            (0xff, 0x84, 0x22),
+            (0x00, 0xeb, 0xab),
+            (0xf6, 0xee, 0x89),
+            (0x00, 0xa3, 0xfe),
+            (0xe1, 0xb0, 0xcb),
+            (0x9a, 0xd2, 0xf2),
+            (0xff, 0xff, 0xff),
+        ],
+    };
+
+    /// The RGB value `color` is displayed as with this palette.
+    ///
+    /// Entries `16..256` of the 256-color palette use the standard xterm color
+    /// cube and grayscale ramp. Returns [`None`] for [`Color::Default`] and
+    /// [`Color::Inherit`] as those depend on the context the color is
+    /// displayed in.
+    pub fn rgb(&self, color: Color) -> Option<(u8, u8, u8)> {
+        const CUBE: [u8; 6] = [0x00, 0x5f, 0x87, 0xaf, 0xd7, 0xff];
+
+        let index = match color {
+            Color::Black => 0,
+            Color::Red => 1,
+            Color::Green => 2,
+            Color::Yellow => 3,
+            Color::Blue => 4,
+            Color::Magenta => 5,
+            Color::Cyan => 6,
+            Color::White => 7,
+            Color::BrightBlack => 8,
+            Color::BrightRed => 9,
+            Color::BrightGreen => 10,
+            Color::BrightYellow => 11,
+            Color::BrightBlue => 12,
+            Color::BrightMagenta => 13,
+            Color::BrightCyan => 14,
+            Color::BrightWhite => 15,
+            Color::Ansi256(index) => index,
+            Color::Rgb(r, g, b) => return Some((r, g, b)),
+            Color::Default | Color::Inherit => return None,
+        };
+
+        Some(match index {
+            0..=15 => self.colors[usize::from(index)],
+            16..=231 => {
+                let index = usize::from(index - 16);
+                (CUBE[index / 36], CUBE[index / 6 % 6], CUBE[index % 6])
+            }
+            232..=255 => {
+                let level = 8 + (index - 232) * 10;
+                (level, level, level)
+            }
+        })
+    }
+
+    /// Replace a named color (or one of the first 16 entries of the 256-color
+    /// palette) with its RGB value from this palette, other colors are
+    /// returned unchanged.
+    fn remap(&self, color: Color) -> Color {
+        match color {
+            Color::Ansi256(16..) | Color::Rgb(..) | Color::Default | Color::Inherit => color,
+            _ => self
+                .rgb(color)
+                .map_or(color, |(r, g, b)| Color::Rgb(r, g, b)),
+        }
+    }
+}
+
+impl Default for Palette {
+    #[inline]
+    fn default() -> Self {
+        Self::XTERM
+    }
+}
+
+/// Replaces the named colors in a style with their RGB values from the
+/// palette.
+///
+/// ```rust
+/// use stylish::{Color, Foreground, Palette, Style};
+///
+/// let style = Style::default().with(Foreground(Color::Red));
+/// assert_eq!(
+///     style.with(Palette::OKABE_ITO).foreground,
+///     Color::Rgb(0xd5, 0x5e, 0x00)
+/// );
+/// ```
+impl Restyle for Palette {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        Style {
+            foreground: self.remap(style.foreground),
+            background: self.remap(style.background),
+            underline_color: self.remap(style.underline_color),
+            ..style
+        }
+    }
+}
@@ -0,0 +1,572 @@
+use core::{fmt, ops::Range, str::FromStr};
+
+use crate::{Color, Intensity, Style, StyleDiff, Underline};
+
+/// The kind of problem encountered while parsing a style, see
+/// [`ParseStyleError::kind`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseStyleErrorKind {
+    /// An attribute name that is not known, such as `fg=red,bolder`
+    UnknownAttribute,
+    /// An attribute that requires a value was given none, such as `fg`
+    MissingValue,
+    /// An attribute that takes no value was given one, such as `bold=true`
+    UnexpectedValue,
+    /// A value that is not a valid color, such as `fg=reddish` or `fg=#f80`
+    InvalidColor,
+    /// A value that is not valid for its attribute, such as `italic=yes`
+    InvalidValue,
+    /// An attribute was empty, such as the middle of `fg=red,,bold`
+    EmptyAttribute,
+}
+
+/// An error returned when parsing a [`Color`], [`Style`] or other style
+/// component from a string fails.
+///
+/// ```rust
+/// use stylish::{ParseStyleErrorKind, StyleDiff};
+///
+/// let error = "fg=red,bolder".parse::<StyleDiff>().unwrap_err();
+/// assert_eq!(error.kind(), ParseStyleErrorKind::UnknownAttribute);
+/// assert_eq!(error.span(), 7..13);
+/// assert_eq!(error.to_string(), "unknown attribute at 7..13");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseStyleError {
+    kind: ParseStyleErrorKind,
+    span: Range<usize>,
+}
+
+impl ParseStyleError {
+    pub(crate) fn new(kind: ParseStyleErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// The kind of problem that was encountered.
+    pub fn kind(&self) -> ParseStyleErrorKind {
+        self.kind
+    }
+
+    /// The byte range within the parsed string that the problem was
+    /// encountered in.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    fn offset(self, offset: usize) -> Self {
+        Self {
+            span: self.span.start + offset..self.span.end + offset,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseStyleErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::UnknownAttribute => "unknown attribute",
+            Self::MissingValue => "missing attribute value",
+            Self::UnexpectedValue => "attribute does not take a value",
+            Self::InvalidColor => "invalid color",
+            Self::InvalidValue => "invalid attribute value",
+            Self::EmptyAttribute => "empty attribute",
+        })
+    }
+}
+
+impl fmt::Display for ParseStyleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl core::error::Error for ParseStyleError {}
+
+const COLORS: [(&str, Color); 18] = [
+    ("black", Color::Black),
+    ("red", Color::Red),
+    ("green", Color::Green),
+    ("yellow", Color::Yellow),
+    ("blue", Color::Blue),
+    ("magenta", Color::Magenta),
+    ("cyan", Color::Cyan),
+    ("white", Color::White),
+    ("bright-black", Color::BrightBlack),
+    ("bright-red", Color::BrightRed),
+    ("bright-green", Color::BrightGreen),
+    ("bright-yellow", Color::BrightYellow),
+    ("bright-blue", Color::BrightBlue),
+    ("bright-magenta", Color::BrightMagenta),
+    ("bright-cyan", Color::BrightCyan),
+    ("bright-white", Color::BrightWhite),
+    ("default", Color::Default),
+    ("inherit", Color::Inherit),
+];
+
+const INTENSITIES: [(&str, Intensity); 3] = [
+    ("normal", Intensity::Normal),
+    ("bold", Intensity::Bold),
+    ("faint", Intensity::Faint),
+];
+
+const UNDERLINES: [(&str, Underline); 6] = [
+    ("none", Underline::None),
+    ("single", Underline::Single),
+    ("double", Underline::Double),
+    ("curly", Underline::Curly),
+    ("dotted", Underline::Dotted),
+    ("dashed", Underline::Dashed),
+];
+
+fn lookup<T: Copy>(table: &[(&str, T)], name: &str) -> Option<T> {
+    table
+        .iter()
+        .find(|&&(n, _)| n == name)
+        .map(|&(_, value)| value)
+}
+
+fn name_of<T: Copy + PartialEq>(table: &[(&'static str, T)], value: T) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|&&(_, v)| v == value)
+        .map(|&(name, _)| name)
+}
+
+pub(crate) fn parse_rgb(s: &str) -> Option<(u8, u8, u8)> {
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let component = |i| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some((component(0)?, component(2)?, component(4)?));
+    }
+
+    let mut components = s
+        .strip_prefix("rgb(")?
+        .strip_suffix(')')?
+        .split(',')
+        .map(|component| parse_u8(component.trim()));
+    let rgb = (
+        components.next()??,
+        components.next()??,
+        components.next()??,
+    );
+    components.next().is_none().then_some(rgb)
+}
+
+/// Like [`u8::from_str`] but without allowing a leading `+`.
+pub(crate) fn parse_u8(s: &str) -> Option<u8> {
+    if s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Parses a color in any of the forms accepted by the `fg`, `bg` and `ul`
+/// attributes.
+///
+/// ```rust
+/// use stylish::Color;
+///
+/// assert_eq!("red".parse(), Ok(Color::Red));
+/// assert_eq!("bright-cyan".parse(), Ok(Color::BrightCyan));
+/// assert_eq!("208".parse(), Ok(Color::Ansi256(208)));
+/// assert_eq!("#ff8800".parse(), Ok(Color::Rgb(0xff, 0x88, 0x00)));
+/// assert_eq!("rgb(255, 136, 0)".parse(), Ok(Color::Rgb(0xff, 0x88, 0x00)));
+/// assert!("reddish".parse::<Color>().is_err());
+/// ```
+impl FromStr for Color {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lookup(&COLORS, s)
+            .or_else(|| parse_u8(s).map(Color::Ansi256))
+            .or_else(|| parse_rgb(s).map(|(r, g, b)| Color::Rgb(r, g, b)))
+            .ok_or_else(|| ParseStyleError::new(ParseStyleErrorKind::InvalidColor, 0..s.len()))
+    }
+}
+
+/// Prints a color in the form accepted by its [`FromStr`] implementation,
+/// with RGB colors printed as `#rrggbb`.
+///
+/// ```rust
+/// use stylish::Color;
+///
+/// assert_eq!(Color::BrightRed.to_string(), "bright-red");
+/// assert_eq!(Color::Ansi256(208).to_string(), "208");
+/// assert_eq!(Color::Rgb(0xff, 0x88, 0x00).to_string(), "#ff8800");
+/// ```
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Color::Ansi256(index) => write!(f, "{index}"),
+            Color::Rgb(r, g, b) => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            color => f.write_str(name_of(&COLORS, color).unwrap_or("default")),
+        }
+    }
+}
+
+impl FromStr for Intensity {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lookup(&INTENSITIES, s)
+            .ok_or_else(|| ParseStyleError::new(ParseStyleErrorKind::InvalidValue, 0..s.len()))
+    }
+}
+
+impl fmt::Display for Intensity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(name_of(&INTENSITIES, *self).unwrap_or("normal"))
+    }
+}
+
+impl FromStr for Underline {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        lookup(&UNDERLINES, s)
+            .ok_or_else(|| ParseStyleError::new(ParseStyleErrorKind::InvalidValue, 0..s.len()))
+    }
+}
+
+impl fmt::Display for Underline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(name_of(&UNDERLINES, *self).unwrap_or("single"))
+    }
+}
+
+fn parse_bool(s: &str) -> Result<bool, ParseStyleError> {
+    match s {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(ParseStyleError::new(
+            ParseStyleErrorKind::InvalidValue,
+            0..s.len(),
+        )),
+    }
+}
+
+/// Splits `s` on commas that are not within parentheses, returning each part
+/// along with its offset.
+fn split_attributes(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut depth = 0usize;
+    let mut start = 0;
+    s.char_indices()
+        .chain([(s.len(), ',')])
+        .filter_map(move |(i, c)| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 || i == s.len() => {
+                    let part = (start, &s[start..i]);
+                    start = i + 1;
+                    return Some(part);
+                }
+                _ => {}
+            }
+            None
+        })
+}
+
+/// Trims whitespace from `s`, returning the trimmed string along with the
+/// offset it starts at.
+fn trim(offset: usize, s: &str) -> (usize, &str) {
+    let trimmed = s.trim_start();
+    (offset + s.len() - trimmed.len(), trimmed.trim_end())
+}
+
+fn parse_attribute<'a>(
+    diff: &mut StyleDiff,
+    offset: usize,
+    s: &'a str,
+    class: &mut impl FnMut(&'a str, Range<usize>) -> Result<(), ParseStyleError>,
+) -> Result<(), ParseStyleError> {
+    use ParseStyleErrorKind::*;
+
+    let (key, value) = match s.split_once('=') {
+        Some((key, value)) => (key, Some(trim(offset + key.len() + 1, value))),
+        None => (s, None),
+    };
+    let (key_offset, key) = trim(offset, key);
+    let key_span = key_offset..key_offset + key.len();
+    let parse = |kind| {
+        let (value_offset, value) =
+            value.ok_or_else(|| ParseStyleError::new(MissingValue, key_span.clone()))?;
+        if value.is_empty() {
+            return Err(ParseStyleError::new(kind, value_offset..value_offset));
+        }
+        Ok((value_offset, value))
+    };
+    let color = || {
+        let (value_offset, value) = parse(InvalidColor)?;
+        value
+            .parse::<Color>()
+            .map_err(|err| err.offset(value_offset))
+    };
+    let no_value = || match value {
+        Some((value_offset, value)) => Err(ParseStyleError::new(
+            UnexpectedValue,
+            value_offset..value_offset + value.len(),
+        )),
+        None => Ok(()),
+    };
+    let flag = |default| match value {
+        None => Ok(default),
+        Some(_) => {
+            let (value_offset, value) = parse(InvalidValue)?;
+            parse_bool(value).map_err(|err| err.offset(value_offset))
+        }
+    };
+
+    match key {
+        "" => return Err(ParseStyleError::new(EmptyAttribute, key_span)),
+        "fg" => diff.foreground = Some(color()?),
+        "bg" => diff.background = Some(color()?),
+        "ul" => diff.underline_color = Some(color()?),
+        "underline" => {
+            diff.underline = Some(match value {
+                None => Underline::Single,
+                Some(_) => {
+                    let (value_offset, value) = parse(InvalidValue)?;
+                    value
+                        .parse()
+                        .map_err(|err: ParseStyleError| err.offset(value_offset))?
+                }
+            })
+        }
+        "italic" => diff.italic = Some(flag(true)?),
+        "strike" | "strikethrough" => diff.strikethrough = Some(flag(true)?),
+        "reverse" => diff.reverse = Some(flag(true)?),
+        "blink" => diff.blink = Some(flag(true)?),
+        "hidden" => diff.hidden = Some(flag(true)?),
+        "overline" => diff.overline = Some(flag(true)?),
+        "class" => {
+            let (value_offset, value) = parse(InvalidValue)?;
+            class(value, value_offset..value_offset + value.len())?;
+        }
+        key if key.starts_with('.') => {
+            no_value()?;
+            if key.len() == 1 {
+                return Err(ParseStyleError::new(MissingValue, key_span));
+            }
+            class(&key[1..], key_span.start + 1..key_span.end)?;
+        }
+        key => {
+            let intensity = lookup(&INTENSITIES, key)
+                .ok_or_else(|| ParseStyleError::new(UnknownAttribute, key_span))?;
+            no_value()?;
+            diff.intensity = Some(intensity);
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a comma separated list of attributes, using the same syntax as
+/// attributes within a format string, see the crate documentation for the
+/// allowed attributes.
+///
+/// Whitespace around attributes, names and values is ignored, and attributes
+/// not mentioned are left as [`None`]. If an attribute is given multiple times
+/// the last value is used.
+///
+/// ```rust
+/// use stylish::{Color, Intensity, StyleDiff};
+///
+/// let diff: StyleDiff = "fg=red, bold, italic=false".parse()?;
+///
+/// let mut expected = StyleDiff::default();
+/// expected.foreground = Some(Color::Red);
+/// expected.intensity = Some(Intensity::Bold);
+/// expected.italic = Some(false);
+/// assert_eq!(diff, expected);
+/// # Ok::<(), stylish::ParseStyleError>(())
+/// ```
+impl FromStr for StyleDiff {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_with_classes(s, |_, span| {
+            Err(ParseStyleError::new(
+                ParseStyleErrorKind::UnknownAttribute,
+                span,
+            ))
+        })
+    }
+}
+
+/// Parses a comma separated list of attributes like [`StyleDiff::from_str`],
+/// additionally allowing theme classes given as `class=name` or `.name`, which
+/// are passed to `class` along with the span of their name.
+#[doc(hidden)]
+pub fn parse_with_classes<'a>(
+    s: &'a str,
+    mut class: impl FnMut(&'a str, Range<usize>) -> Result<(), ParseStyleError>,
+) -> Result<StyleDiff, ParseStyleError> {
+    let mut diff = StyleDiff::default();
+    let mut attributes = split_attributes(s).enumerate().peekable();
+    while let Some((index, (offset, attribute))) = attributes.next() {
+        // Allow an empty string, or a single trailing comma
+        let last = attributes.peek().is_none();
+        if last && attribute.trim().is_empty() && (index > 0 || s.trim().is_empty()) {
+            break;
+        }
+        parse_attribute(&mut diff, offset, attribute, &mut class)?;
+    }
+    Ok(diff)
+}
+
+/// Prints the changes in this diff as a comma separated list of attributes,
+/// in the syntax accepted by its [`FromStr`] implementation.
+///
+/// ```rust
+/// use stylish::{Color, StyleDiff, Underline};
+///
+/// let mut diff = StyleDiff::default();
+/// diff.foreground = Some(Color::Default);
+/// diff.underline = Some(Underline::Curly);
+/// diff.strikethrough = Some(false);
+///
+/// assert_eq!(diff.to_string(), "fg=default,underline=curly,strike=false");
+/// assert_eq!(diff.to_string().parse(), Ok(diff));
+/// ```
+impl fmt::Display for StyleDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        let mut attribute = |f: &mut fmt::Formatter<'_>, args: fmt::Arguments<'_>| {
+            let result = write!(f, "{separator}{args}");
+            separator = ",";
+            result
+        };
+        let flag = |name: &'static str, value: Option<bool>| {
+            value.map(move |value| (name, if value { "" } else { "=false" }))
+        };
+
+        if let Some(color) = self.foreground {
+            attribute(f, format_args!("fg={color}"))?;
+        }
+        if let Some(color) = self.background {
+            attribute(f, format_args!("bg={color}"))?;
+        }
+        if let Some(intensity) = self.intensity {
+            attribute(f, format_args!("{intensity}"))?;
+        }
+        if let Some((name, value)) = flag("italic", self.italic) {
+            attribute(f, format_args!("{name}{value}"))?;
+        }
+        match self.underline {
+            Some(Underline::Single) => attribute(f, format_args!("underline"))?,
+            Some(underline) => attribute(f, format_args!("underline={underline}"))?,
+            None => {}
+        }
+        if let Some(color) = self.underline_color {
+            attribute(f, format_args!("ul={color}"))?;
+        }
+        for (name, value) in [
+            flag("strike", self.strikethrough),
+            flag("reverse", self.reverse),
+            flag("blink", self.blink),
+            flag("hidden", self.hidden),
+            flag("overline", self.overline),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            attribute(f, format_args!("{name}{value}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a comma separated list of attributes, applying them to
+/// [`Style::default`], see the [`StyleDiff`] implementation for details.
+///
+/// ```rust
+/// use stylish::{Color, Foreground, Intensity, Style};
+///
+/// assert_eq!(
+///     "fg=green,faint".parse(),
+///     Ok(Style::default().with(Foreground(Color::Green)).with(Intensity::Faint)),
+/// );
+/// ```
+impl FromStr for Style {
+    type Err = ParseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Style::default().with(s.parse::<StyleDiff>()?))
+    }
+}
+
+/// Prints the attributes that differ from [`Style::default`], in the syntax
+/// accepted by its [`FromStr`] implementation.
+///
+/// ```rust
+/// use stylish::{Color, Foreground, Intensity, Style};
+///
+/// let style = Style::default()
+///     .with(Foreground(Color::Rgb(0xff, 0x88, 0x00)))
+///     .with(Intensity::Bold);
+///
+/// assert_eq!(style.to_string(), "fg=#ff8800,bold");
+/// assert_eq!(style.to_string().parse(), Ok(style));
+/// ```
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.diff_from(Style::default()).fmt(f)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use core::{fmt, marker::PhantomData, str::FromStr};
+
+    use serde::{
+        de::{self, Unexpected, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    };
+
+    use crate::{Color, Intensity, ParseStyleError, Underline};
+
+    struct FromStrVisitor<T>(&'static str, PhantomData<T>);
+
+    impl<T: FromStr<Err = ParseStyleError>> Visitor<'_> for FromStrVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+            value
+                .parse()
+                .map_err(|_| E::invalid_value(Unexpected::Str(value), &self))
+        }
+    }
+
+    macro_rules! impl_via_str {
+        ($($ty:ty => $expecting:literal,)*) => {
+            $(
+                impl Serialize for $ty {
+                    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        serializer.collect_str(self)
+                    }
+                }
+
+                impl<'de> Deserialize<'de> for $ty {
+                    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        deserializer.deserialize_str(FromStrVisitor($expecting, PhantomData))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_via_str! {
+        Color => "a color name, palette index, `#rrggbb` or `rgb(r, g, b)`",
+        Intensity => "one of `normal`, `bold` or `faint`",
+        Underline => "one of `none`, `single`, `double`, `curly`, `dotted` or `dashed`",
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{Restyle, Style};
+
+/// A [`Restyle`] implementor that swaps [`Style::foreground`] and
+/// [`Style::background`], for rendering reverse video on outputs that don't
+/// support [`Style::reverse`].
+///
+/// ```rust
+/// use stylish::{Background, Color, Foreground, Style, Swap};
+///
+/// let style = Style::default()
+///     .with(Foreground(Color::Red))
+///     .with(Background(Color::Blue));
+///
+/// let mut expected = Style::default();
+/// expected.foreground = Color::Blue;
+/// expected.background = Color::Red;
+///
+/// assert_eq!(style.with(Swap), expected);
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Swap;
+
+/// A [`Restyle`] implementor that resets all attributes back to
+/// [`Style::default`].
+///
+/// ```rust
+/// use stylish::{Color, Foreground, Intensity, Reset, Style};
+///
+/// let style = Style::default()
+///     .with(Foreground(Color::Red))
+///     .with(Intensity::Bold);
+///
+/// assert_eq!(style.with(Reset), Style::default());
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Reset;
+
+/// A [`Restyle`] implementor that only applies the wrapped restyle when the
+/// condition is `true`.
+///
+/// ```rust
+/// use stylish::{Intensity, Style, When};
+///
+/// let mut expected = Style::default();
+/// expected.intensity = Intensity::Bold;
+///
+/// assert_eq!(Style::default().with(When(true, Intensity::Bold)), expected);
+/// assert_eq!(
+///     Style::default().with(When(false, Intensity::Bold)),
+///     Style::default()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct When<R>(pub bool, pub R);
+
+/// A [`Restyle`] implementor that calls a closure to modify the style.
+///
+/// ```rust
+/// use stylish::{Color, Foreground, RestyleFn, Style};
+///
+/// let darken = RestyleFn(|style: Style| match style.foreground {
+///     Color::BrightRed => style.with(Foreground(Color::Red)),
+///     _ => style,
+/// });
+///
+/// let mut expected = Style::default();
+/// expected.foreground = Color::Red;
+///
+/// assert_eq!(
+///     Style::default()
+///         .with(Foreground(Color::BrightRed))
+///         .with(&darken),
+///     expected
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct RestyleFn<F>(pub F);
+
+impl Restyle for Swap {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        Style {
+            foreground: style.background,
+            background: style.foreground,
+            ..style
+        }
+    }
+}
+
+impl Restyle for Reset {
+    #[inline]
+    fn apply(&self, _style: Style) -> Style {
+        Style::default()
+    }
+}
+
+impl<R: Restyle> Restyle for When<R> {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        let When(condition, restyle) = self;
+        if *condition {
+            restyle.apply(style)
+        } else {
+            style
+        }
+    }
+}
+
+impl<F: Fn(Style) -> Style> Restyle for RestyleFn<F> {
+    #[inline]
+    fn apply(&self, style: Style) -> Style {
+        (self.0)(style)
+    }
+}
@@ -0,0 +1,248 @@
+use core::ops::Range;
+
+use crate::{
+    depth::NAMED,
+    parse::{parse_u8, ParseStyleError, ParseStyleErrorKind},
+    Color, Intensity, Style, StyleDiff, Underline,
+};
+
+/// A diff that changes every attribute back to its default value.
+pub(crate) fn reset() -> StyleDiff {
+    let style = Style::default();
+    StyleDiff {
+        foreground: Some(style.foreground),
+        background: Some(style.background),
+        intensity: Some(style.intensity),
+        italic: Some(style.italic),
+        underline: Some(style.underline),
+        underline_color: Some(style.underline_color),
+        strikethrough: Some(style.strikethrough),
+        reverse: Some(style.reverse),
+        blink: Some(style.blink),
+        hidden: Some(style.hidden),
+        overline: Some(style.overline),
+    }
+}
+
+/// Splits `s` on `separator`, returning each part along with its offset
+/// (starting from `offset`).
+fn parts(offset: usize, s: &str, separator: char) -> impl Iterator<Item = (usize, &str)> + Clone {
+    s.split(separator).scan(offset, move |position, part| {
+        let start = *position;
+        *position += part.len() + separator.len_utf8();
+        Some((start, part))
+    })
+}
+
+/// Parses a numeric parameter, an empty parameter defaults to `0`.
+fn number(offset: usize, s: &str, kind: ParseStyleErrorKind) -> Result<u8, ParseStyleError> {
+    if s.is_empty() {
+        return Ok(0);
+    }
+    parse_u8(s).ok_or_else(|| ParseStyleError::new(kind, offset..offset + s.len()))
+}
+
+/// Parses the parameters following a `38`, `48` or `58` extended color code,
+/// either `5;index` or `2;r;g;b` (or the colon separated equivalents, which
+/// may include a color space id as `2:id:r:g:b`).
+fn extended_color<'a>(
+    code: Range<usize>,
+    values: &mut (impl Iterator<Item = (usize, &'a str)> + Clone),
+    colon: bool,
+) -> Result<Color, ParseStyleError> {
+    use ParseStyleErrorKind::*;
+
+    let (offset, kind) = values
+        .next()
+        .ok_or_else(|| ParseStyleError::new(MissingValue, code.clone()))?;
+    let kind_span = offset..offset + kind.len();
+
+    if kind == "2" && colon && values.clone().count() == 4 {
+        values.next();
+    }
+
+    let mut next = || {
+        let (offset, value) = values
+            .next()
+            .ok_or_else(|| ParseStyleError::new(MissingValue, code.start..kind_span.end))?;
+        number(offset, value, InvalidColor)
+    };
+
+    match kind {
+        "5" => Ok(Color::Ansi256(next()?)),
+        "2" => Ok(Color::Rgb(next()?, next()?, next()?)),
+        _ => Err(ParseStyleError::new(InvalidColor, kind_span)),
+    }
+}
+
+/// Parses SGR parameters like [`StyleDiff::from_sgr`], with the spans of
+/// errors starting from `offset`.
+#[doc(hidden)]
+pub fn parse_sgr(offset: usize, s: &str) -> Result<StyleDiff, ParseStyleError> {
+    use ParseStyleErrorKind::*;
+
+    let mut diff = StyleDiff::default();
+    let mut params = parts(offset, s, ';');
+    while let Some((offset, param)) = params.next() {
+        let colon = param.contains(':');
+        let mut subparams = parts(offset, param, ':');
+        let (_, code) = subparams.next().unwrap_or((offset, param));
+        let span = offset..offset + code.len();
+        let code = number(offset, code, UnknownAttribute)?;
+
+        match code {
+            0 => diff = reset(),
+            1 => diff.intensity = Some(Intensity::Bold),
+            2 => diff.intensity = Some(Intensity::Faint),
+            22 => diff.intensity = Some(Intensity::Normal),
+            3 | 23 => diff.italic = Some(code == 3),
+            4 => {
+                diff.underline = Some(match subparams.next() {
+                    None => Underline::Single,
+                    Some((offset, style)) => match number(offset, style, InvalidValue)? {
+                        0 => Underline::None,
+                        1 => Underline::Single,
+                        2 => Underline::Double,
+                        3 => Underline::Curly,
+                        4 => Underline::Dotted,
+                        5 => Underline::Dashed,
+                        _ => {
+                            return Err(ParseStyleError::new(
+                                InvalidValue,
+                                offset..offset + style.len(),
+                            ))
+                        }
+                    },
+                })
+            }
+            21 => diff.underline = Some(Underline::Double),
+            24 => diff.underline = Some(Underline::None),
+            5 | 6 | 25 => diff.blink = Some(code != 25),
+            7 | 27 => diff.reverse = Some(code == 7),
+            8 | 28 => diff.hidden = Some(code == 8),
+            9 | 29 => diff.strikethrough = Some(code == 9),
+            53 | 55 => diff.overline = Some(code == 53),
+            30..=37 => diff.foreground = Some(NAMED[usize::from(code - 30)]),
+            90..=97 => diff.foreground = Some(NAMED[usize::from(code - 90 + 8)]),
+            39 => diff.foreground = Some(Color::Default),
+            40..=47 => diff.background = Some(NAMED[usize::from(code - 40)]),
+            100..=107 => diff.background = Some(NAMED[usize::from(code - 100 + 8)]),
+            49 => diff.background = Some(Color::Default),
+            59 => diff.underline_color = Some(Color::Default),
+            38 | 48 | 58 => {
+                let color = if colon {
+                    extended_color(span, &mut subparams, true)?
+                } else {
+                    extended_color(span, &mut params, false)?
+                };
+                match code {
+                    38 => diff.foreground = Some(color),
+                    48 => diff.background = Some(color),
+                    _ => diff.underline_color = Some(color),
+                }
+            }
+            _ => return Err(ParseStyleError::new(UnknownAttribute, span)),
+        }
+
+        if let Some((extra, _)) = subparams.next() {
+            return Err(ParseStyleError::new(
+                UnexpectedValue,
+                extra..offset + param.len(),
+            ));
+        }
+    }
+
+    Ok(diff)
+}
+
+impl StyleDiff {
+    /// Parses the parameters of an SGR (Select Graphic Rendition) escape
+    /// sequence, the `;` separated numbers between `\x1b[` and `m`, as used by
+    /// environment variables like `LS_COLORS`.
+    ///
+    /// This supports all the parameters written by the ANSI writers, along
+    /// with some common alternatives (such as `6` for blinking, `21` for a
+    /// double underline, and extended colors separated by `:`). Parameters may
+    /// have leading zeros, and an empty parameter is treated as `0`, which
+    /// resets every attribute to its default.
+    ///
+    /// ```rust
+    /// use stylish::{Color, Intensity, ParseStyleErrorKind, StyleDiff, Underline};
+    ///
+    /// let diff = StyleDiff::from_sgr("01;34;48;5;208;4:3")?;
+    /// assert_eq!(diff, "bold, fg=blue, bg=208, underline=curly".parse()?);
+    ///
+    /// let diff = StyleDiff::from_sgr("38;2;255;136;0")?;
+    /// assert_eq!(diff.foreground, Some(Color::Rgb(0xff, 0x88, 0x00)));
+    ///
+    /// let diff = StyleDiff::from_sgr("0;1")?;
+    /// assert_eq!(diff.intensity, Some(Intensity::Bold));
+    /// assert_eq!(diff.underline, Some(Underline::None));
+    ///
+    /// let error = StyleDiff::from_sgr("1;38;5").unwrap_err();
+    /// assert_eq!(error.kind(), ParseStyleErrorKind::MissingValue);
+    /// assert_eq!(error.span(), 2..6);
+    /// # Ok::<(), stylish::ParseStyleError>(())
+    /// ```
+    pub fn from_sgr(s: &str) -> Result<StyleDiff, ParseStyleError> {
+        parse_sgr(0, s)
+    }
+}
+
+/// An iterator over the entries of a `:` separated list of `key=sgr`
+/// entries, as used by the `LS_COLORS`, `GREP_COLORS` and `GCC_COLORS`
+/// environment variables.
+///
+/// Each entry's value is parsed with [`StyleDiff::from_sgr`], entries without
+/// a value (such as the `ne` flag in `GREP_COLORS`) are returned with
+/// [`None`], and empty entries are skipped. The spans of errors are relative
+/// to the whole list.
+///
+/// ```rust
+/// use stylish::SgrList;
+///
+/// let mut list = SgrList::new("di=01;34:*.tar=31:ne:");
+/// assert_eq!(list.next(), Some(Ok(("di", Some("bold, fg=blue".parse()?)))));
+/// assert_eq!(list.next(), Some(Ok(("*.tar", Some("fg=red".parse()?)))));
+/// assert_eq!(list.next(), Some(Ok(("ne", None))));
+/// assert_eq!(list.next(), None);
+///
+/// let error = SgrList::new("di=01;34:ln=01;360").nth(1).unwrap().unwrap_err();
+/// assert_eq!(error.span(), 15..18);
+/// # Ok::<(), stylish::ParseStyleError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct SgrList<'a> {
+    s: &'a str,
+    position: usize,
+}
+
+impl<'a> SgrList<'a> {
+    /// Iterate over the entries of `s`.
+    pub fn new(s: &'a str) -> Self {
+        Self { s, position: 0 }
+    }
+}
+
+impl<'a> Iterator for SgrList<'a> {
+    type Item = Result<(&'a str, Option<StyleDiff>), ParseStyleError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(rest) = self.s.get(self.position..) {
+            let start = self.position;
+            let entry = rest.split(':').next().unwrap_or(rest);
+            self.position += entry.len() + 1;
+
+            match entry.split_once('=') {
+                Some((key, value)) => {
+                    return Some(
+                        parse_sgr(start + key.len() + 1, value).map(|diff| (key, Some(diff))),
+                    )
+                }
+                None if entry.is_empty() => continue,
+                None => return Some(Ok((entry, None))),
+            }
+        }
+        None
+    }
+}
@@ -1,36 +1,36 @@
 mod debug_struct {
-    use stylish::{plain::format, fmt};
+    use stylish::{format_args, plain::format};
 
     #[test]
     fn test_empty() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_struct("Foo").finish()
             }
         }
 
-        assert_eq!("Foo", format!("{:?}", Foo));
-        assert_eq!("Foo", format!("{:#?}", Foo));
+        assert_eq!("Foo", format!("{:s?}", Foo));
+        assert_eq!("Foo", format!("{:#s?}", Foo));
     }
 
     #[test]
     fn test_single() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_struct("Foo").field("bar", &true).finish()
             }
         }
 
-        assert_eq!("Foo { bar: true }", format!("{:?}", Foo));
+        assert_eq!("Foo { bar: true }", format!("{:s?}", Foo));
         assert_eq!(
             "Foo {
     bar: true,
 }",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -38,8 +38,8 @@ mod debug_struct {
     fn test_multiple() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_struct("Foo")
                     .field("bar", &true)
                     .field("baz", &format_args!("{}/{}", 10, 20))
@@ -47,13 +47,13 @@ mod debug_struct {
             }
         }
 
-        assert_eq!("Foo { bar: true, baz: 10/20 }", format!("{:?}", Foo));
+        assert_eq!("Foo { bar: true, baz: 10/20 }", format!("{:s?}", Foo));
         assert_eq!(
             "Foo {
     bar: true,
     baz: 10/20,
 }",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -61,8 +61,8 @@ mod debug_struct {
     fn test_nested() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_struct("Foo")
                     .field("bar", &true)
                     .field("baz", &format_args!("{}/{}", 10, 20))
@@ -72,15 +72,18 @@ mod debug_struct {
 
         struct Bar;
 
-        impl fmt::Debug for Bar {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt.debug_struct("Bar").field("foo", &Foo).field("hello", &"world").finish()
+        impl stylish::Debug for Bar {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
+                fmt.debug_struct("Bar")
+                    .field("foo", &Foo)
+                    .field("hello", &"world")
+                    .finish()
             }
         }
 
         assert_eq!(
             "Bar { foo: Foo { bar: true, baz: 10/20 }, hello: \"world\" }",
-            format!("{:?}", Bar)
+            format!("{:s?}", Bar)
         );
         assert_eq!(
             "Bar {
@@ -90,7 +93,7 @@ mod debug_struct {
     },
     hello: \"world\",
 }",
-            format!("{:#?}", Bar)
+            format!("{:#s?}", Bar)
         );
     }
 
@@ -98,18 +101,18 @@ mod debug_struct {
     fn test_only_non_exhaustive() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_struct("Foo").finish_non_exhaustive()
             }
         }
 
-        assert_eq!("Foo { .. }", format!("{:?}", Foo));
+        assert_eq!("Foo { .. }", format!("{:s?}", Foo));
         assert_eq!(
             "Foo {
     ..
 }",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -117,8 +120,8 @@ mod debug_struct {
     fn test_multiple_and_non_exhaustive() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_struct("Foo")
                     .field("bar", &true)
                     .field("baz", &format_args!("{}/{}", 10, 20))
@@ -126,14 +129,14 @@ mod debug_struct {
             }
         }
 
-        assert_eq!("Foo { bar: true, baz: 10/20, .. }", format!("{:?}", Foo));
+        assert_eq!("Foo { bar: true, baz: 10/20, .. }", format!("{:s?}", Foo));
         assert_eq!(
             "Foo {
     bar: true,
     baz: 10/20,
     ..
 }",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -141,8 +144,8 @@ mod debug_struct {
     fn test_nested_non_exhaustive() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_struct("Foo")
                     .field("bar", &true)
                     .field("baz", &format_args!("{}/{}", 10, 20))
@@ -152,8 +155,8 @@ mod debug_struct {
 
         struct Bar;
 
-        impl fmt::Debug for Bar {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Bar {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_struct("Bar")
                     .field("foo", &Foo)
                     .field("hello", &"world")
@@ -163,7 +166,7 @@ mod debug_struct {
 
         assert_eq!(
             "Bar { foo: Foo { bar: true, baz: 10/20, .. }, hello: \"world\", .. }",
-            format!("{:?}", Bar)
+            format!("{:s?}", Bar)
         );
         assert_eq!(
             "Bar {
@@ -175,44 +178,44 @@ mod debug_struct {
     hello: \"world\",
     ..
 }",
-            format!("{:#?}", Bar)
+            format!("{:#s?}", Bar)
         );
     }
 }
 
 mod debug_tuple {
-    use std::fmt;
+    use stylish::{format_args, plain::format};
 
     #[test]
     fn test_empty() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_tuple("Foo").finish()
             }
         }
 
-        assert_eq!("Foo", format!("{:?}", Foo));
-        assert_eq!("Foo", format!("{:#?}", Foo));
+        assert_eq!("Foo", format!("{:s?}", Foo));
+        assert_eq!("Foo", format!("{:#s?}", Foo));
     }
 
     #[test]
     fn test_single() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_tuple("Foo").field(&true).finish()
             }
         }
 
-        assert_eq!("Foo(true)", format!("{:?}", Foo));
+        assert_eq!("Foo(true)", format!("{:s?}", Foo));
         assert_eq!(
             "Foo(
     true,
 )",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -220,19 +223,22 @@ mod debug_tuple {
     fn test_multiple() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt.debug_tuple("Foo").field(&true).field(&format_args!("{}/{}", 10, 20)).finish()
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
+                fmt.debug_tuple("Foo")
+                    .field(&true)
+                    .field(&format_args!("{}/{}", 10, 20))
+                    .finish()
             }
         }
 
-        assert_eq!("Foo(true, 10/20)", format!("{:?}", Foo));
+        assert_eq!("Foo(true, 10/20)", format!("{:s?}", Foo));
         assert_eq!(
             "Foo(
     true,
     10/20,
 )",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -240,21 +246,24 @@ mod debug_tuple {
     fn test_nested() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt.debug_tuple("Foo").field(&true).field(&format_args!("{}/{}", 10, 20)).finish()
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
+                fmt.debug_tuple("Foo")
+                    .field(&true)
+                    .field(&format_args!("{}/{}", 10, 20))
+                    .finish()
             }
         }
 
         struct Bar;
 
-        impl fmt::Debug for Bar {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Bar {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_tuple("Bar").field(&Foo).field(&"world").finish()
             }
         }
 
-        assert_eq!("Bar(Foo(true, 10/20), \"world\")", format!("{:?}", Bar));
+        assert_eq!("Bar(Foo(true, 10/20), \"world\")", format!("{:s?}", Bar));
         assert_eq!(
             "Bar(
     Foo(
@@ -263,55 +272,55 @@ mod debug_tuple {
     ),
     \"world\",
 )",
-            format!("{:#?}", Bar)
+            format!("{:#s?}", Bar)
         );
     }
 }
 
 mod debug_map {
-    use std::fmt;
+    use stylish::{format_args, plain::format};
 
     #[test]
     fn test_empty() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_map().finish()
             }
         }
 
-        assert_eq!("{}", format!("{:?}", Foo));
-        assert_eq!("{}", format!("{:#?}", Foo));
+        assert_eq!("{}", format!("{:s?}", Foo));
+        assert_eq!("{}", format!("{:#s?}", Foo));
     }
 
     #[test]
     fn test_single() {
         struct Entry;
 
-        impl fmt::Debug for Entry {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Entry {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_map().entry(&"bar", &true).finish()
             }
         }
 
         struct KeyValue;
 
-        impl fmt::Debug for KeyValue {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for KeyValue {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_map().key(&"bar").value(&true).finish()
             }
         }
 
-        assert_eq!(format!("{:?}", Entry), format!("{:?}", KeyValue));
-        assert_eq!(format!("{:#?}", Entry), format!("{:#?}", KeyValue));
+        assert_eq!(format!("{:s?}", Entry), format!("{:s?}", KeyValue));
+        assert_eq!(format!("{:#s?}", Entry), format!("{:#s?}", KeyValue));
 
-        assert_eq!("{\"bar\": true}", format!("{:?}", Entry));
+        assert_eq!("{\"bar\": true}", format!("{:s?}", Entry));
         assert_eq!(
             "{
     \"bar\": true,
 }",
-            format!("{:#?}", Entry)
+            format!("{:#s?}", Entry)
         );
     }
 
@@ -319,8 +328,8 @@ mod debug_map {
     fn test_multiple() {
         struct Entry;
 
-        impl fmt::Debug for Entry {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Entry {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_map()
                     .entry(&"bar", &true)
                     .entry(&10, &format_args!("{}/{}", 10, 20))
@@ -330,8 +339,8 @@ mod debug_map {
 
         struct KeyValue;
 
-        impl fmt::Debug for KeyValue {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for KeyValue {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_map()
                     .key(&"bar")
                     .value(&true)
@@ -341,16 +350,16 @@ mod debug_map {
             }
         }
 
-        assert_eq!(format!("{:?}", Entry), format!("{:?}", KeyValue));
-        assert_eq!(format!("{:#?}", Entry), format!("{:#?}", KeyValue));
+        assert_eq!(format!("{:s?}", Entry), format!("{:s?}", KeyValue));
+        assert_eq!(format!("{:#s?}", Entry), format!("{:#s?}", KeyValue));
 
-        assert_eq!("{\"bar\": true, 10: 10/20}", format!("{:?}", Entry));
+        assert_eq!("{\"bar\": true, 10: 10/20}", format!("{:s?}", Entry));
         assert_eq!(
             "{
     \"bar\": true,
     10: 10/20,
 }",
-            format!("{:#?}", Entry)
+            format!("{:#s?}", Entry)
         );
     }
 
@@ -358,8 +367,8 @@ mod debug_map {
     fn test_nested() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_map()
                     .entry(&"bar", &true)
                     .entry(&10, &format_args!("{}/{}", 10, 20))
@@ -369,16 +378,19 @@ mod debug_map {
 
         struct Bar;
 
-        impl fmt::Debug for Bar {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt.debug_map().entry(&"foo", &Foo).entry(&Foo, &"world").finish()
+        impl stylish::Debug for Bar {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
+                fmt.debug_map()
+                    .entry(&"foo", &Foo)
+                    .entry(&Foo, &"world")
+                    .finish()
             }
         }
 
         assert_eq!(
             "{\"foo\": {\"bar\": true, 10: 10/20}, \
                     {\"bar\": true, 10: 10/20}: \"world\"}",
-            format!("{:?}", Bar)
+            format!("{:s?}", Bar)
         );
         assert_eq!(
             "{
@@ -391,31 +403,30 @@ mod debug_map {
         10: 10/20,
     }: \"world\",
 }",
-            format!("{:#?}", Bar)
+            format!("{:#s?}", Bar)
         );
     }
 
     #[test]
     fn test_entry_err() {
         // Ensure errors in a map entry don't trigger panics (#65231)
-        use std::fmt::Write;
 
         struct ErrorFmt;
 
-        impl fmt::Debug for ErrorFmt {
-            fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
-                Err(fmt::Error)
+        impl stylish::Debug for ErrorFmt {
+            fn fmt(&self, _: &mut stylish::Formatter<'_>) -> stylish::Result {
+                Err(stylish::Error)
             }
         }
 
         struct KeyValue<K, V>(usize, K, V);
 
-        impl<K, V> fmt::Debug for KeyValue<K, V>
+        impl<K, V> stylish::Debug for KeyValue<K, V>
         where
-            K: fmt::Debug,
-            V: fmt::Debug,
+            K: stylish::Debug,
+            V: stylish::Debug,
         {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 let mut map = fmt.debug_map();
 
                 for _ in 0..self.0 {
@@ -426,13 +437,13 @@ mod debug_map {
             }
         }
 
-        let mut buf = String::new();
+        let mut buf = stylish::plain(String::new());
 
-        assert!(write!(&mut buf, "{:?}", KeyValue(1, ErrorFmt, "bar")).is_err());
-        assert!(write!(&mut buf, "{:?}", KeyValue(1, "foo", ErrorFmt)).is_err());
+        assert!(stylish::write!(buf, "{:s?}", KeyValue(1, ErrorFmt, "bar")).is_err());
+        assert!(stylish::write!(buf, "{:s?}", KeyValue(1, "foo", ErrorFmt)).is_err());
 
-        assert!(write!(&mut buf, "{:?}", KeyValue(2, ErrorFmt, "bar")).is_err());
-        assert!(write!(&mut buf, "{:?}", KeyValue(2, "foo", ErrorFmt)).is_err());
+        assert!(stylish::write!(buf, "{:s?}", KeyValue(2, ErrorFmt, "bar")).is_err());
+        assert!(stylish::write!(buf, "{:s?}", KeyValue(2, "foo", ErrorFmt)).is_err());
     }
 
     #[test]
@@ -440,13 +451,13 @@ mod debug_map {
     fn test_invalid_key_when_entry_is_incomplete() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_map().key(&"bar").key(&"invalid").finish()
             }
         }
 
-        format!("{:?}", Foo);
+        format!("{:s?}", Foo);
     }
 
     #[test]
@@ -454,13 +465,13 @@ mod debug_map {
     fn test_invalid_finish_incomplete_entry() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_map().key(&"bar").finish()
             }
         }
 
-        format!("{:?}", Foo);
+        format!("{:s?}", Foo);
     }
 
     #[test]
@@ -468,49 +479,49 @@ mod debug_map {
     fn test_invalid_value_before_key() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_map().value(&"invalid").key(&"bar").finish()
             }
         }
 
-        format!("{:?}", Foo);
+        format!("{:s?}", Foo);
     }
 }
 
 mod debug_set {
-    use std::fmt;
+    use stylish::{format_args, plain::format};
 
     #[test]
     fn test_empty() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_set().finish()
             }
         }
 
-        assert_eq!("{}", format!("{:?}", Foo));
-        assert_eq!("{}", format!("{:#?}", Foo));
+        assert_eq!("{}", format!("{:s?}", Foo));
+        assert_eq!("{}", format!("{:#s?}", Foo));
     }
 
     #[test]
     fn test_single() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_set().entry(&true).finish()
             }
         }
 
-        assert_eq!("{true}", format!("{:?}", Foo));
+        assert_eq!("{true}", format!("{:s?}", Foo));
         assert_eq!(
             "{
     true,
 }",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -518,19 +529,22 @@ mod debug_set {
     fn test_multiple() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt.debug_set().entry(&true).entry(&format_args!("{}/{}", 10, 20)).finish()
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
+                fmt.debug_set()
+                    .entry(&true)
+                    .entry(&format_args!("{}/{}", 10, 20))
+                    .finish()
             }
         }
 
-        assert_eq!("{true, 10/20}", format!("{:?}", Foo));
+        assert_eq!("{true, 10/20}", format!("{:s?}", Foo));
         assert_eq!(
             "{
     true,
     10/20,
 }",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -538,21 +552,24 @@ mod debug_set {
     fn test_nested() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt.debug_set().entry(&true).entry(&format_args!("{}/{}", 10, 20)).finish()
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
+                fmt.debug_set()
+                    .entry(&true)
+                    .entry(&format_args!("{}/{}", 10, 20))
+                    .finish()
             }
         }
 
         struct Bar;
 
-        impl fmt::Debug for Bar {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Bar {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_set().entry(&Foo).entry(&"world").finish()
             }
         }
 
-        assert_eq!("{{true, 10/20}, \"world\"}", format!("{:?}", Bar));
+        assert_eq!("{{true, 10/20}, \"world\"}", format!("{:s?}", Bar));
         assert_eq!(
             "{
     {
@@ -561,44 +578,44 @@ mod debug_set {
     },
     \"world\",
 }",
-            format!("{:#?}", Bar)
+            format!("{:#s?}", Bar)
         );
     }
 }
 
 mod debug_list {
-    use std::fmt;
+    use stylish::{format_args, plain::format};
 
     #[test]
     fn test_empty() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_list().finish()
             }
         }
 
-        assert_eq!("[]", format!("{:?}", Foo));
-        assert_eq!("[]", format!("{:#?}", Foo));
+        assert_eq!("[]", format!("{:s?}", Foo));
+        assert_eq!("[]", format!("{:#s?}", Foo));
     }
 
     #[test]
     fn test_single() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_list().entry(&true).finish()
             }
         }
 
-        assert_eq!("[true]", format!("{:?}", Foo));
+        assert_eq!("[true]", format!("{:s?}", Foo));
         assert_eq!(
             "[
     true,
 ]",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -606,19 +623,22 @@ mod debug_list {
     fn test_multiple() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt.debug_list().entry(&true).entry(&format_args!("{}/{}", 10, 20)).finish()
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
+                fmt.debug_list()
+                    .entry(&true)
+                    .entry(&format_args!("{}/{}", 10, 20))
+                    .finish()
             }
         }
 
-        assert_eq!("[true, 10/20]", format!("{:?}", Foo));
+        assert_eq!("[true, 10/20]", format!("{:s?}", Foo));
         assert_eq!(
             "[
     true,
     10/20,
 ]",
-            format!("{:#?}", Foo)
+            format!("{:#s?}", Foo)
         );
     }
 
@@ -626,21 +646,24 @@ mod debug_list {
     fn test_nested() {
         struct Foo;
 
-        impl fmt::Debug for Foo {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt.debug_list().entry(&true).entry(&format_args!("{}/{}", 10, 20)).finish()
+        impl stylish::Debug for Foo {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
+                fmt.debug_list()
+                    .entry(&true)
+                    .entry(&format_args!("{}/{}", 10, 20))
+                    .finish()
             }
         }
 
         struct Bar;
 
-        impl fmt::Debug for Bar {
-            fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl stylish::Debug for Bar {
+            fn fmt(&self, fmt: &mut stylish::Formatter<'_>) -> stylish::Result {
                 fmt.debug_list().entry(&Foo).entry(&"world").finish()
             }
         }
 
-        assert_eq!("[[true, 10/20], \"world\"]", format!("{:?}", Bar));
+        assert_eq!("[[true, 10/20], \"world\"]", format!("{:s?}", Bar));
         assert_eq!(
             "[
     [
@@ -649,7 +672,7 @@ mod debug_list {
     ],
     \"world\",
 ]",
-            format!("{:#?}", Bar)
+            format!("{:#s?}", Bar)
         );
     }
 }
@@ -657,7 +680,11 @@ mod debug_list {
 #[test]
 fn test_formatting_parameters_are_forwarded() {
     use std::collections::{BTreeMap, BTreeSet};
+
+    use stylish::plain::format;
+
     #[derive(Debug)]
+    #[allow(dead_code)]
     struct Foo {
         bar: u32,
         baz: u32,
@@ -672,13 +699,13 @@ fn test_formatting_parameters_are_forwarded() {
     set.insert(1024);
     set.insert(7);
 
-    assert_eq!(format!("{:03?}", struct_), "Foo { bar: 1024, baz: 007 }");
-    assert_eq!(format!("{:03?}", tuple), "(1024, 007)");
-    assert_eq!(format!("{:03?}", list), "[1024, 007]");
-    assert_eq!(format!("{:03?}", map), r#"{"bar": 1024, "baz": 007}"#);
-    assert_eq!(format!("{:03?}", set), "{007, 1024}");
+    assert_eq!(format!("{:03s?}", struct_), "Foo { bar: 1024, baz: 007 }");
+    assert_eq!(format!("{:03s?}", tuple), "(1024, 007)");
+    assert_eq!(format!("{:03s?}", list), "[1024, 007]");
+    assert_eq!(format!("{:03s?}", map), r#"{"bar": 1024, "baz": 007}"#);
+    assert_eq!(format!("{:03s?}", set), "{007, 1024}");
     assert_eq!(
-        format!("{:#03?}", struct_),
+        format!("{:#03s?}", struct_),
         "
 Foo {
     bar: 1024,
@@ -688,7 +715,7 @@ Foo {
         .trim()
     );
     assert_eq!(
-        format!("{:#03?}", tuple),
+        format!("{:#03s?}", tuple),
         "
 (
     1024,
@@ -698,7 +725,7 @@ Foo {
         .trim()
     );
     assert_eq!(
-        format!("{:#03?}", list),
+        format!("{:#03s?}", list),
         "
 [
     1024,
@@ -708,7 +735,7 @@ Foo {
         .trim()
     );
     assert_eq!(
-        format!("{:#03?}", map),
+        format!("{:#03s?}", map),
         r#"
 {
     "bar": 1024,
@@ -718,7 +745,7 @@ Foo {
         .trim()
     );
     assert_eq!(
-        format!("{:#03?}", set),
+        format!("{:#03s?}", set),
         "
 {
     007,
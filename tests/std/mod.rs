@@ -1,6 +1,6 @@
 use stylish::plain::format;
 
-// mod builders;
+mod builders;
 mod float;
 mod num;
 
@@ -177,6 +177,1434 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ansi256() {
+        assert_eq!(
+            stylish::ansi::format!("{:(fg=208,bg=17)}", 2),
+            "\x1b[38;5;208;48;5;17m2\x1b[0m"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(fg=208,bg=17)}", 2),
+            "<span style=color:#ff8700;background-color:#00005f>2</span>"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(fg=1)}{:(fg=244)}", 2, 3),
+            "<span style=color:#cd0000>2</span><span style=color:#808080>3</span>"
+        );
+        assert_eq!(stylish::plain::format!("{:(fg=208,bg=17)}", 2), "2");
+    }
+
+    #[test]
+    fn rgb() {
+        assert_eq!(
+            stylish::ansi::format!("{:(fg=#ff8800,bg=rgb(1, 2, 3))}", 2),
+            "\x1b[38;2;255;136;0;48;2;1;2;3m2\x1b[0m"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(fg=#ff8800,bg=rgb(1,2,3))}", 2),
+            "<span style=color:#ff8800;background-color:#010203>2</span>"
+        );
+        assert_eq!(stylish::plain::format!("{:(fg=#FF8800)}", 2), "2");
+    }
+
+    #[test]
+    fn attributes() {
+        assert_eq!(
+            stylish::ansi::format!("{:(italic,underline,strike)}", 2),
+            "\x1b[3;4;9m2\x1b[0m"
+        );
+        assert_eq!(
+            stylish::ansi::format!("{:(reverse,blink,hidden,overline)}", 2),
+            "\x1b[5;7;8;53m2\x1b[0m"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(italic,underline,strike,overline)}", 2),
+            "<span style=font-style:italic;text-decoration-line:underline overline line-through>2</span>"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(reverse,hidden)}", 2),
+            "<span style=filter:invert(100%);visibility:hidden>2</span>"
+        );
+        assert_eq!(stylish::plain::format!("{:(italic,underline)}", 2), "2");
+    }
+
+    #[test]
+    fn attributes_reset() {
+        assert_eq!(
+            stylish::ansi::format!("{:(italic,underline,fg=red)}{:(fg=red)}", "both", "red"),
+            "\x1b[31;3;4mboth\x1b[23;24mred\x1b[0m"
+        );
+        assert_eq!(
+            stylish::ansi::format!("{:(strikethrough=true,italic=false)}", 2),
+            "\x1b[9m2\x1b[0m"
+        );
+        assert_eq!(
+            stylish::ansi::format!("{:(underline=single,underline=none)}", 2),
+            "2"
+        );
+    }
+
+    #[test]
+    fn underline_style() {
+        assert_eq!(
+            stylish::ansi::format!("{:(underline=curly,ul=red)}", 2),
+            "\x1b[4:3;58;5;1m2\x1b[0m"
+        );
+        assert_eq!(
+            stylish::ansi::format!("{:(underline=dashed,ul=#ff8800)}", 2),
+            "\x1b[4:5;58;2;255;136;0m2\x1b[0m"
+        );
+        assert_eq!(
+            stylish::ansi::format!("{:(underline=double,ul=21)}{:(underline)}", 2, 3),
+            "\x1b[4:2;58;5;21m2\x1b[4;59m3\x1b[0m"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(underline=curly,ul=red)}", 2),
+            "<span style=text-decoration-line:underline;text-decoration-style:wavy;text-decoration-color:red>2</span>"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(underline=dotted)}", 2),
+            "<span style=text-decoration-line:underline;text-decoration-style:dotted>2</span>"
+        );
+        assert_eq!(
+            stylish::plain::format!("{:(underline=curly,ul=red)}", 2),
+            "2"
+        );
+    }
+
+    #[test]
+    fn restyle_combinators() {
+        use stylish::{
+            Background, Blink, Color, Foreground, Hidden, Intensity, Italic, Overline, Reset,
+            RestyleFn, Reverse, Strikethrough, Style, StyleDiff, Swap, Underline, UnderlineColor,
+            When,
+        };
+
+        let all = (
+            Foreground(Color::Red),
+            Background(Color::Blue),
+            Intensity::Bold,
+            Italic(true),
+            Underline::Curly,
+            UnderlineColor(Color::Green),
+            Strikethrough(true),
+            Reverse(true),
+            Blink(true),
+            Hidden(true),
+            Overline(true),
+            When(false, Reset),
+        );
+        let style = Style::default().with(all);
+        assert_eq!(
+            style.to_string(),
+            "fg=red,bg=blue,bold,italic,underline=curly,ul=green,strike,reverse,blink,hidden,overline"
+        );
+        assert_eq!(style.with(When(true, Reset)), Style::default());
+        assert_eq!(style.with((Swap, Swap)), style);
+        assert_eq!(
+            style.with(Swap).diff_from(style),
+            "fg=blue, bg=red".parse().unwrap()
+        );
+
+        let mute = RestyleFn(|style: Style| style.with((Intensity::Faint, Blink(false))));
+        assert_eq!(
+            style.with(mute).diff_from(style),
+            "faint, blink=false".parse().unwrap()
+        );
+
+        let first: StyleDiff = "fg=red, bold, underline".parse().unwrap();
+        let second: StyleDiff = "fg=blue, normal, italic".parse().unwrap();
+        assert_eq!(
+            first.merge(second),
+            "fg=blue, normal, underline, italic".parse().unwrap()
+        );
+        assert_eq!(
+            Style::default().with(first.merge(second)),
+            Style::default().with(first).with(second)
+        );
+        assert_eq!(first.merge(StyleDiff::default()), first);
+
+        let inverse = first.inverse(style);
+        assert_eq!(inverse, "fg=red, bold, underline=curly".parse().unwrap());
+        assert_eq!(style.with(first).with(inverse), style);
+    }
+
+    #[test]
+    fn inherit() {
+        use stylish::{Color, Foreground, Style, StyleDiff, Write};
+
+        let inner = stylish::format_args!("{:(fg=inherit,bold)}{:(fg=default)}", 1, 2);
+        assert_eq!(
+            stylish::ansi::format!("{:(fg=red)s}", inner),
+            "\x1b[31;1m1\x1b[0m2"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(fg=red)s}", inner),
+            "<span style=color:red;font-weight:bolder>1</span>2"
+        );
+
+        let mut style = Style::default();
+        style.foreground = Color::Inherit;
+        let mut writer = stylish::html(String::new());
+        writer.write_str("1", style).unwrap();
+        writer.write_str("2", Style::default()).unwrap();
+        assert_eq!(writer.finish().unwrap(), "12");
+        assert_eq!(style.diff_from(Style::default()), StyleDiff::default());
+
+        let red = Style::default().with(Foreground(Color::Red));
+        assert_eq!(red.with(style), red);
+
+        let diff: StyleDiff = "fg=inherit".parse().unwrap();
+        assert_eq!(diff.foreground, Some(Color::Inherit));
+        assert_eq!(diff.to_string(), "fg=inherit");
+        assert_eq!(red.with(diff), red);
+        assert_eq!(diff.inverse(red), StyleDiff::default());
+        let blue: StyleDiff = "fg=blue".parse().unwrap();
+        assert_eq!(blue.merge(diff), blue);
+        assert_eq!(diff.merge(blue), blue);
+    }
+
+    #[test]
+    fn color_depth() {
+        use stylish::{Color, ColorDepth};
+
+        let mut writer = stylish::Ansi::new(String::new()).depth(ColorDepth::Ansi256);
+        stylish::write!(writer, "{:(fg=#ff8000,bg=red,ul=rgb(1,1,1))}", 1).unwrap();
+        assert_eq!(
+            writer.finish().unwrap(),
+            "\x1b[38;5;208;41;58;5;16m1\x1b[0m"
+        );
+
+        let mut writer = stylish::io::Ansi::new(Vec::new()).depth(ColorDepth::NoColor);
+        stylish::write!(writer, "{:(fg=#ff8000)} {:(fg=208,bold)}", 1, 2).unwrap();
+        assert_eq!(writer.finish().unwrap(), b"1 \x1b[1m2\x1b[0m");
+
+        assert!(ColorDepth::NoColor < ColorDepth::Ansi16);
+        assert!(ColorDepth::Ansi256 < ColorDepth::default());
+        for index in 0..=255 {
+            let rgb = stylish::Palette::XTERM.rgb(Color::Ansi256(index)).unwrap();
+            if index >= 16 {
+                assert_eq!(
+                    Color::Rgb(rgb.0, rgb.1, rgb.2).to_ansi256(),
+                    Color::Ansi256(index)
+                );
+            }
+            assert_eq!(
+                Color::Rgb(rgb.0, rgb.1, rgb.2).to_ansi16(),
+                Color::Ansi256(index).to_ansi16()
+            );
+        }
+        assert_eq!(
+            ColorDepth::Ansi16.downsample(Color::Inherit),
+            Color::Inherit
+        );
+        assert_eq!(
+            ColorDepth::NoColor.downsample(Color::Inherit),
+            Color::Inherit
+        );
+    }
+
+    #[test]
+    fn color_choice() {
+        use std::{collections::HashMap, ffi::OsString};
+
+        use stylish::{
+            io::{Auto, ColorChoice},
+            ColorDepth,
+        };
+
+        fn resolve(
+            choice: ColorChoice,
+            is_terminal: bool,
+            vars: &[(&str, &str)],
+        ) -> Option<ColorDepth> {
+            let vars: HashMap<&str, OsString> = vars.iter().map(|&(k, v)| (k, v.into())).collect();
+            choice.resolve(is_terminal, |name| vars.get(name).cloned())
+        }
+
+        use ColorChoice::{Always, Auto as Detect, Never};
+        use ColorDepth::{Ansi16, Ansi256, TrueColor};
+
+        assert_eq!(resolve(Detect, true, &[]), Some(Ansi16));
+        assert_eq!(resolve(Detect, false, &[]), None);
+        assert_eq!(resolve(Detect, true, &[("TERM", "dumb")]), None);
+        assert_eq!(resolve(Detect, true, &[("NO_COLOR", "1")]), None);
+        assert_eq!(resolve(Detect, true, &[("NO_COLOR", "")]), Some(Ansi16));
+        assert_eq!(resolve(Detect, true, &[("CLICOLOR", "0")]), None);
+        assert_eq!(
+            resolve(Detect, false, &[("CLICOLOR_FORCE", "1")]),
+            Some(Ansi16)
+        );
+        assert_eq!(resolve(Detect, false, &[("CLICOLOR_FORCE", "0")]), None);
+        assert_eq!(
+            resolve(Detect, true, &[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]),
+            None
+        );
+        assert_eq!(
+            resolve(Detect, true, &[("TERM", "xterm-256color")]),
+            Some(Ansi256)
+        );
+        assert_eq!(
+            resolve(
+                Detect,
+                true,
+                &[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]
+            ),
+            Some(TrueColor)
+        );
+        assert_eq!(resolve(Always, false, &[("NO_COLOR", "1")]), Some(Ansi16));
+        assert_eq!(resolve(Never, true, &[("CLICOLOR_FORCE", "1")]), None);
+
+        for choice in [Detect, Always, Never] {
+            assert_eq!(choice.to_string().parse(), Ok(choice));
+        }
+        assert_eq!(
+            "sometimes".parse::<ColorChoice>().unwrap_err().to_string(),
+            "expected `auto`, `always` or `never`"
+        );
+
+        let mut writer = Auto::with_depth(Vec::new(), Some(Ansi256));
+        assert_eq!(writer.color_depth(), Some(Ansi256));
+        stylish::write!(writer, "{:(fg=#ff8000)}", 1).unwrap();
+        assert_eq!(writer.finish().unwrap(), b"\x1b[38;5;208m1\x1b[0m");
+
+        let file = std::fs::File::open(file!()).unwrap();
+        let writer = Auto::new(file, Always);
+        assert!(writer.color_depth().is_some());
+    }
+
+    #[test]
+    fn color_math() {
+        use stylish::{Background, Color, Foreground, MinContrast, Palette, Style};
+
+        for index in 0..=255 {
+            let (r, g, b) = Palette::XTERM.rgb(Color::Ansi256(index)).unwrap();
+            let rgb = Color::Rgb(r, g, b);
+            assert_eq!(Color::from(rgb.to_hsl().unwrap()), rgb);
+            assert_eq!(Color::from(rgb.to_hsv().unwrap()), rgb);
+            assert_eq!(Color::from(rgb.to_oklch().unwrap()), rgb);
+            assert_eq!(rgb.mix(Color::Ansi256(index), 0.5), rgb);
+        }
+        assert_eq!(Color::Default.to_hsl(), None);
+        assert_eq!(Color::Inherit.to_oklch(), None);
+        assert_eq!(Color::Default.readable_foreground(), Color::Default);
+
+        let dark = Color::Rgb(0x30, 0x30, 0x30);
+        let light = Color::Rgb(0xe0, 0xe0, 0xe0);
+        for (foreground, background) in [(dark, dark), (light, light), (Color::Blue, Color::Black)]
+        {
+            let style = Style::default()
+                .with(Foreground(foreground))
+                .with(Background(background))
+                .with(MinContrast(7.0));
+            assert!(style.foreground.contrast_ratio(background).unwrap() >= 7.0);
+            assert_eq!(style.background, background);
+        }
+
+        let style = Style::default().with(Foreground(Color::Blue));
+        assert_eq!(style.with(MinContrast(21.0)), style);
+    }
+
+    #[test]
+    fn restyled() {
+        use stylish::{Color, Deficiency, Palette, Restyled, Themed};
+
+        let theme = [("ok", "fg=green".parse().unwrap())];
+        let mut writer = Themed::new(
+            Restyled::new(stylish::html(String::new()), Palette::OKABE_ITO),
+            theme,
+        );
+        stylish::write!(writer, "{:(.ok)} {:(fg=208,bg=default)}", 1, 2).unwrap();
+        assert_eq!(
+            writer.into_inner().into_inner().finish().unwrap(),
+            "<span style=color:#009e73>1</span> <span style=color:#ff8700>2</span>"
+        );
+
+        let mut writer = Restyled::new(stylish::html(String::new()), Deficiency::Deuteranopia);
+        stylish::write!(writer, "{:(fg=red)} {:(fg=green)}", "fail", "ok").unwrap();
+        assert_eq!(
+            writer.into_inner().finish().unwrap(),
+            "<span style=color:#827300>fail</span> <span style=color:#c0ac2d>ok</span>"
+        );
+
+        let mut writer = Restyled::new(stylish::ansi(String::new()), Deficiency::Protanopia);
+        stylish::write!(writer, "{:(fg=red)} {:(fg=green)}", "fail", "ok").unwrap();
+        assert_eq!(
+            writer.into_inner().finish().unwrap(),
+            "\x1b[38;2;86;75;0mfail\x1b[0m \x1b[38;2;210;184;0mok\x1b[0m"
+        );
+
+        let mut writer =
+            stylish::io::Restyled::new(stylish::io::ansi(Vec::new()), Deficiency::Tritanopia);
+        stylish::write!(writer, "{:(fg=blue)} {:(fg=yellow)}", "fail", "ok").unwrap();
+        assert_eq!(
+            writer.into_inner().finish().unwrap(),
+            b"\x1b[38;2;0;100;139mfail\x1b[0m \x1b[38;2;221;191;174mok\x1b[0m"
+        );
+
+        // Remapping to a palette before simulating shows how the palette
+        // looks to someone with the deficiency
+        let mut writer = Restyled::new(
+            Restyled::new(stylish::html(String::new()), Deficiency::Deuteranopia),
+            Palette::OKABE_ITO,
+        );
+        stylish::write!(writer, "{:(fg=red)} {:(fg=green)}", "fail", "ok").unwrap();
+        assert_eq!(
+            writer.into_inner().into_inner().finish().unwrap(),
+            "<span style=color:#9e8c00>fail</span> <span style=color:#8a8676>ok</span>"
+        );
+
+        let mut writer = stylish::io::Restyled::new(
+            stylish::io::Restyled::new(stylish::io::ansi(Vec::new()), Deficiency::Protanopia),
+            Palette::OKABE_ITO,
+        );
+        stylish::write!(writer, "{:(fg=red)} {:(fg=green)}", "fail", "ok").unwrap();
+        assert_eq!(
+            writer.into_inner().into_inner().finish().unwrap(),
+            b"\x1b[38;2;129;113;0mfail\x1b[0m \x1b[38;2;154;146;113mok\x1b[0m"
+        );
+
+        for deficiency in [
+            Deficiency::Protanopia,
+            Deficiency::Deuteranopia,
+            Deficiency::Tritanopia,
+        ] {
+            for gray in [0, 0x80, 0xff] {
+                let gray = Color::Rgb(gray, gray, gray);
+                let simulated = deficiency.simulate(gray);
+                assert!(simulated.contrast_ratio(gray).unwrap() < 1.05);
+            }
+            assert_eq!(deficiency.simulate(Color::Default), Color::Default);
+        }
+    }
+
+    #[test]
+    fn gradient() {
+        use stylish::{Color, Gradient};
+
+        let colors = [
+            Color::Rgb(0xff, 0x00, 0x00),
+            Color::Rgb(0x00, 0xff, 0x00),
+            Color::Rgb(0x00, 0x00, 0xff),
+        ];
+
+        // Graphemes are colored as a whole, and each stop is hit exactly
+        assert_eq!(
+            stylish::html::format!("{:s}", Gradient::new("e\u{301}xy", &colors)),
+            "<span style=color:#ff0000>e\u{301}</span>\
+             <span style=color:#00ff00>x</span>\
+             <span style=color:#0000ff>y</span>",
+        );
+
+        assert_eq!(
+            stylish::html::format!("{:s}", Gradient::new("x", &colors)),
+            "<span style=color:#ff0000>x</span>",
+        );
+        assert_eq!(
+            stylish::html::format!("{:s}", Gradient::new("ab", &[Color::Blue])),
+            "<span style=color:blue>ab</span>",
+        );
+        assert_eq!(
+            stylish::html::format!("{:s}", Gradient::new("ab", &[])),
+            "ab"
+        );
+        assert_eq!(
+            stylish::html::format!("{:s}", Gradient::new("", &colors)),
+            ""
+        );
+        assert_eq!(
+            stylish::plain::format!("{:s}", Gradient::rainbow("rainbow")),
+            "rainbow"
+        );
+    }
+
+    #[test]
+    fn sgr() {
+        use stylish::{
+            Color, Intensity, ParseStyleErrorKind, SgrList, Style, StyleDiff, Underline,
+        };
+
+        // Every style the ANSI writer emits parses back to the same style, except
+        // named underline colors which are written as palette indices
+        for attributes in [
+            "fg=red,bg=bright-cyan,ul=4",
+            "fg=208,bg=17,ul=255",
+            "fg=#ff8800,bg=#000001,ul=#123456",
+            "fg=black,bg=white,ul=15",
+            "bold,italic,underline,blink,reverse,hidden,strike,overline",
+            "faint,underline=double",
+            "underline=curly",
+            "underline=dotted",
+            "underline=dashed",
+        ] {
+            let style: Style = attributes.parse().unwrap();
+            let output = {
+                let mut writer = stylish::Ansi::new(String::new());
+                stylish::Write::write_str(&mut writer, "x", style).unwrap();
+                writer.finish().unwrap()
+            };
+            let sgr = output
+                .strip_prefix("\x1b[")
+                .and_then(|rest| rest.strip_suffix("mx\x1b[0m"))
+                .unwrap();
+            assert_eq!(
+                Style::default().with(StyleDiff::from_sgr(sgr).unwrap()),
+                style,
+                "{sgr}"
+            );
+        }
+
+        let diff = StyleDiff::from_sgr("1;4;2;22;24;3;23;5;25;6;7;27;8;28;9;29;53;55;21").unwrap();
+        assert_eq!(
+            diff,
+            "normal,underline=double,italic=false,blink,reverse=false,hidden=false,strike=false,overline=false"
+                .parse()
+                .unwrap()
+        );
+        assert_eq!(
+            StyleDiff::from_sgr("38:5:208;48:2:1:2:3;58:2::4:5:6;4:0").unwrap(),
+            "fg=208,bg=#010203,ul=#040506,underline=none"
+                .parse()
+                .unwrap()
+        );
+        assert_eq!(
+            StyleDiff::from_sgr("31;;1").unwrap(),
+            StyleDiff::from_sgr("0;1").unwrap()
+        );
+        assert_eq!(
+            StyleDiff::from_sgr("0").unwrap(),
+            "fg=default,bg=default,ul=default,normal,italic=false,underline=none,\
+             blink=false,reverse=false,hidden=false,strike=false,overline=false"
+                .parse()
+                .unwrap()
+        );
+        assert_eq!(
+            StyleDiff::from_sgr("39;49;59").unwrap(),
+            "fg=default,bg=default,ul=default".parse().unwrap()
+        );
+        assert_eq!(
+            StyleDiff::from_sgr("97;107").unwrap(),
+            "fg=bright-white,bg=bright-white".parse().unwrap()
+        );
+        assert_eq!(
+            StyleDiff::from_sgr("38;5;256").unwrap_err().kind(),
+            ParseStyleErrorKind::InvalidColor
+        );
+        for (sgr, kind, span) in [
+            ("1;12", ParseStyleErrorKind::UnknownAttribute, 2..4),
+            ("1;x", ParseStyleErrorKind::UnknownAttribute, 2..3),
+            ("38", ParseStyleErrorKind::MissingValue, 0..2),
+            ("38;2;1;2", ParseStyleErrorKind::MissingValue, 0..4),
+            ("38;3;1", ParseStyleErrorKind::InvalidColor, 3..4),
+            ("4:6", ParseStyleErrorKind::InvalidValue, 2..3),
+            ("1:2", ParseStyleErrorKind::UnexpectedValue, 2..3),
+            ("38:5:1:2", ParseStyleErrorKind::UnexpectedValue, 7..8),
+        ] {
+            let error = StyleDiff::from_sgr(sgr).unwrap_err();
+            assert_eq!((error.kind(), error.span()), (kind, span), "{sgr}");
+        }
+
+        let entries: Vec<_> = SgrList::new(
+            "rs=0:di=01;34:ln=01;36:*.tar=01;31:ms=01;31:sl=:ne:error=01;31:locus=01::",
+        )
+        .collect::<Result<_, _>>()
+        .unwrap();
+        assert_eq!(entries.len(), 9);
+        assert_eq!(entries[1], ("di", Some("bold,fg=blue".parse().unwrap())));
+        assert_eq!(entries[3].0, "*.tar");
+        assert_eq!(entries[6], ("ne", None));
+        assert_eq!(entries[7], ("error", Some("bold,fg=red".parse().unwrap())));
+        assert_eq!(SgrList::new("").next(), None);
+
+        let git = |s: &str| StyleDiff::from_git_color(s);
+        assert_eq!(
+            git("BrightRed blue Bold nodim no-ul").unwrap(),
+            "fg=bright-red,bg=blue,normal,underline=none"
+                .parse()
+                .unwrap()
+        );
+        assert_eq!(
+            git(" 208  #123456 italic blink reverse strike ").unwrap(),
+            "fg=208,bg=#123456,italic,blink,reverse,strike"
+                .parse()
+                .unwrap()
+        );
+        assert_eq!(
+            git("normal default").unwrap(),
+            "bg=default".parse().unwrap()
+        );
+        assert_eq!(git("-1 black").unwrap().background, Some(Color::Black));
+        assert_eq!(
+            Style::default().with(git("bold reset red").unwrap()),
+            "fg=red,bold".parse().unwrap()
+        );
+        assert_eq!(git("reset").unwrap().underline, Some(Underline::None));
+        assert_eq!(git("dim").unwrap().intensity, Some(Intensity::Faint));
+        assert_eq!(git("").unwrap(), StyleDiff::default());
+        let error = git("red blue green").unwrap_err();
+        assert_eq!(
+            (error.kind(), error.span()),
+            (ParseStyleErrorKind::InvalidColor, 9..14)
+        );
+        assert_eq!(
+            git("#12345").unwrap_err().kind(),
+            ParseStyleErrorKind::UnknownAttribute
+        );
+    }
+
+    #[test]
+    fn ls_colors() {
+        use std::{fs, path::Path};
+
+        use stylish::{LsColors, StyleDiff};
+
+        let root = std::env::temp_dir().join(format!("stylish-ls-colors-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("dir")).unwrap();
+        fs::write(root.join("dir/archive.TAR"), "").unwrap();
+        fs::write(root.join("dir/notes.txt"), "").unwrap();
+
+        let colors: LsColors = "no=03:di=01;34:fi=32:mi=05:*.tar=31:*.TXT=36:lc=\\e[:ec=x"
+            .parse()
+            .unwrap();
+        let style = |path: &Path| colors.style(path);
+        let diff = |s: &str| s.parse::<StyleDiff>().unwrap();
+
+        assert_eq!(style(&root.join("dir")), diff("italic,bold,fg=blue"));
+        assert_eq!(style(&root.join("dir/archive.TAR")), diff("italic,fg=red"));
+        assert_eq!(style(&root.join("dir/notes.txt")), diff("italic,fg=cyan"));
+        assert_eq!(style(&root.join("missing")), diff("italic,blink"));
+
+        let path = root.join("dir/archive.TAR");
+        let prefix = root.to_str().unwrap().trim_end_matches('/');
+        let styled = stylish::html::format!("{:s}", colors.path(&path).file_name_only(true));
+        assert_eq!(
+            styled,
+            format!("{prefix}/dir/<span style=color:red;font-style:italic>archive.TAR</span>")
+        );
+        let styled = stylish::html::format!("{:s}", colors.path(&path));
+        assert!(
+            styled.ends_with(
+                "<span style=color:blue;font-weight:bolder;font-style:italic>dir</span>/\
+                 <span style=color:red;font-style:italic>archive.TAR</span>"
+            ),
+            "{styled}"
+        );
+        let styled = stylish::plain::format!("{:s}", colors.path(Path::new("a//b/")));
+        assert_eq!(styled, "a//b/");
+
+        assert_eq!(
+            "di=01;34:ln=01;36:ex=01;3x"
+                .parse::<LsColors>()
+                .unwrap_err()
+                .span(),
+            24..26
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{symlink, PermissionsExt};
+
+            fs::write(root.join("script"), "").unwrap();
+            fs::set_permissions(root.join("script"), fs::Permissions::from_mode(0o755)).unwrap();
+            fs::create_dir(root.join("tmp")).unwrap();
+            fs::set_permissions(root.join("tmp"), fs::Permissions::from_mode(0o1777)).unwrap();
+            fs::create_dir(root.join("shared")).unwrap();
+            fs::set_permissions(root.join("shared"), fs::Permissions::from_mode(0o777)).unwrap();
+            symlink(root.join("dir"), root.join("link")).unwrap();
+            symlink(root.join("missing"), root.join("orphan")).unwrap();
+
+            let defaults = LsColors::default();
+            let style = |path: &str| defaults.style(&root.join(path));
+            assert_eq!(style("script"), diff("bold,fg=green"));
+            assert_eq!(style("tmp"), diff("fg=black,bg=green"));
+            assert_eq!(style("shared"), diff("fg=blue,bg=green"));
+            assert_eq!(style("link"), diff("bold,fg=cyan"));
+            assert_eq!(style("orphan"), diff("bold,fg=cyan"));
+            assert_eq!(style("dir/notes.txt"), StyleDiff::default());
+
+            let colors: LsColors = "ln=target:or=31".parse().unwrap();
+            let style = |path: &str| colors.style(&root.join(path));
+            assert_eq!(style("link"), diff("bold,fg=blue"));
+            assert_eq!(style("orphan"), diff("fg=red"));
+        }
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn style_macros() {
+        use stylish::{Color, Intensity, Style, StyleDiff, Underline};
+
+        const STYLE: Style = Style::new()
+            .foreground(Color::Rgb(1, 2, 3))
+            .background(Color::Ansi256(17))
+            .intensity(Intensity::Faint)
+            .italic(true)
+            .underline(Underline::Dotted)
+            .underline_color(Color::BrightRed)
+            .strikethrough(true)
+            .reverse(true)
+            .blink(true)
+            .hidden(true)
+            .overline(true);
+        const DIFF: StyleDiff = StyleDiff::new().background(Color::Default).italic(false);
+        static MACRO_STYLE: Style = stylish::style!(
+            fg = rgb(1, 2, 3),
+            bg = 17,
+            faint,
+            italic,
+            underline = dotted,
+            ul = bright - red,
+            strike,
+            reverse,
+            blink,
+            hidden,
+            overline,
+        );
+
+        assert_eq!(Style::new(), Style::default());
+        assert_eq!(StyleDiff::new(), StyleDiff::default());
+        assert_eq!(
+            STYLE,
+            "fg=#010203,bg=17,faint,italic,underline=dotted,ul=bright-red,strike,reverse,blink,hidden,overline"
+                .parse()
+                .unwrap()
+        );
+        assert_eq!(MACRO_STYLE, STYLE);
+        assert_eq!(DIFF, "bg=default,italic=false".parse().unwrap());
+        assert_eq!(stylish::style_diff!(bg = default, italic = false), DIFF);
+        assert_eq!(stylish::style_diff!(), StyleDiff::default());
+        assert_eq!(stylish::style!(), Style::default());
+        assert_eq!(
+            stylish::style!(fg = inherit, bold),
+            Style::new().intensity(Intensity::Bold)
+        );
+        assert_eq!(
+            stylish::style_diff!(fg = inherit).foreground,
+            Some(Color::Inherit)
+        );
+        assert_eq!(
+            stylish::style_diff!("fg=#00ff00, underline"),
+            StyleDiff::new()
+                .foreground(Color::Rgb(0, 0xff, 0))
+                .underline(Underline::Single)
+        );
+    }
+
+    #[test]
+    fn lit() {
+        use stylish::{Color, Intensity, StyleDiff, StyledStr};
+
+        const GREETING: StyledStr<'static> = stylish::lit!(
+            "{{hello}} {:(fg=red,bold)}{}{x:(italic)}",
+            "world",
+            '!',
+            x = 42
+        );
+
+        assert_eq!(
+            GREETING.spans(),
+            &[
+                ("{hello} ", StyleDiff::new()),
+                (
+                    "world",
+                    StyleDiff::new()
+                        .foreground(Color::Red)
+                        .intensity(Intensity::Bold)
+                ),
+                ("!", StyleDiff::new()),
+                ("42", StyleDiff::new().italic(true)),
+            ]
+        );
+        assert_eq!(
+            stylish::html::format!("{:s}", GREETING),
+            stylish::html::format!("{{hello}} {:(fg=red,bold)}!{:(italic)}", "world", 42),
+        );
+        assert_eq!(
+            stylish::html::format!("{:(bg=blue)s}", GREETING),
+            stylish::html::format!(
+                "{:(bg=blue)}{:(bg=blue,fg=red,bold)}{:(bg=blue)}{:(bg=blue,italic)}",
+                "{hello} ",
+                "world",
+                "!",
+                42
+            ),
+        );
+
+        assert_eq!(
+            stylish::lit!("{1:(fg=blue)}{0:(fg=blue)}{:(fg=blue)}", "a", true).spans(),
+            &[("trueaa", StyleDiff::new().foreground(Color::Blue))]
+        );
+        assert_eq!(stylish::lit!("{}", "").spans(), &[]);
+
+        // Attributes set back to their defaults reset the surrounding style
+        assert_eq!(
+            stylish::html::format!(
+                "{:(fg=red,bold)s}",
+                stylish::lit!("{}{:(fg=default)}{:(normal)}", "a", "b", "c")
+            ),
+            stylish::html::format!(
+                "{:(fg=red,bold)s}",
+                stylish::format_args!("{}{:(fg=default)}{:(normal)}", "a", "b", "c")
+            ),
+        );
+        assert_eq!(
+            stylish::html::format!(
+                "{:(fg=red,bold)s}",
+                stylish::lit!("{}{:(fg=default)}{:(normal)}", "a", "b", "c")
+            ),
+            "<span style=color:red;font-weight:bolder>a</span>\
+            <span style=font-weight:bolder>b</span>\
+            <span style=color:red>c</span>",
+        );
+    }
+
+    #[test]
+    fn padding() {
+        let args = stylish::format_args!("{:(fg=red)}b", "a");
+        let width = 6;
+
+        assert_eq!(stylish::plain::format!("[{:5s}]", args), "[ab   ]");
+        assert_eq!(stylish::plain::format!("[{:>5s}]", args), "[   ab]");
+        assert_eq!(stylish::plain::format!("[{:-^7s}]", args), "[--ab---]");
+        assert_eq!(stylish::plain::format!("[{:1s}]", args), "[ab]");
+        assert_eq!(stylish::plain::format!("[{:.1s}]", args), "[a]");
+        assert_eq!(stylish::plain::format!("[{:_<4.1s}]", args), "[a___]");
+        assert_eq!(
+            stylish::plain::format!("[{:>1$s}|{0:<width$s}]", args, 4),
+            "[  ab|ab    ]"
+        );
+        let wide = stylish::lit!("日本");
+        assert_eq!(
+            stylish::plain::format!("[{:^6s}|{0:.3s}]", wide),
+            "[ 日本 |日]"
+        );
+
+        assert_eq!(
+            stylish::html::format!("[{:>4(bg=blue)s}]", args),
+            "[  <span style=color:red;background-color:blue>a</span><span style=background-color:blue>b</span>]"
+        );
+        assert_eq!(
+            stylish::html::format!("[{:(bg=blue)>4s}]", args),
+            "[<span style=background-color:blue>  </span><span style=color:red;background-color:blue>a</span><span style=background-color:blue>b</span>]"
+        );
+        assert_eq!(
+            stylish::html::format!("[{:(fg=green)>1$(bold)s}]", args, 3),
+            "[<span style=color:green> </span>\
+            <span style=color:red;font-weight:bolder>a</span>\
+            <span style=color:green;font-weight:bolder>b</span>]"
+        );
+
+        assert_eq!(stylish::plain::format!("[{:*>5}]", 12), "[***12]");
+        assert_eq!(stylish::plain::format!("[{:(<5}]", 12), "[12(((]");
+        assert_eq!(stylish::plain::format!("[{:.>6.2}]", 1.2345), "[..1.23]");
+        assert_eq!(stylish::plain::format!("[{:*^9?}]", Some(1)), "[*Some(1)*]");
+        assert_eq!(stylish::plain::format!("[{:*>05}]", -1), "[-0001]");
+        assert_eq!(
+            stylish::html::format!("[{:*>3(fg=red)}]", 1),
+            "[<span style=color:red>**1</span>]"
+        );
+    }
+
+    #[test]
+    fn padding_formats_once() {
+        use std::cell::Cell;
+
+        // Writes how many times it has been formatted
+        struct Counter(Cell<usize>);
+
+        impl Counter {
+            fn next(&self) -> usize {
+                self.0.set(self.0.get() + 1);
+                self.0.get()
+            }
+        }
+
+        impl std::fmt::Display for Counter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.next())
+            }
+        }
+
+        impl std::fmt::Debug for Counter {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "#{}", self.next())
+            }
+        }
+
+        impl stylish::Display for Counter {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.with(stylish::Intensity::Bold)
+                    .write_str(&"x".repeat(self.next()))
+            }
+        }
+
+        let counter = Counter(Cell::new(0));
+        assert_eq!(stylish::plain::format!("[{:*>5}]", counter), "[****1]");
+        assert_eq!(stylish::plain::format!("[{:*^5?}]", counter), "[*#2**]");
+        assert_eq!(
+            stylish::html::format!("[{:-<4.2s}]", counter),
+            "[<span style=font-weight:bolder>xx</span>--]"
+        );
+        assert_eq!(stylish::plain::format!("[{:5.3s}]", counter), "[xxx  ]");
+        assert_eq!(counter.0.get(), 4);
+    }
+
+    #[test]
+    fn formatter_accessors() {
+        struct Name(&'static str);
+
+        impl stylish::Display for Name {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.with(stylish::Intensity::Bold).pad(self.0)
+            }
+        }
+
+        struct Num(i64);
+
+        impl stylish::Display for Num {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.pad_integral(self.0 >= 0, "0b", &format!("{:b}", self.0.unsigned_abs()))
+            }
+        }
+
+        struct Layout(&'static str);
+
+        impl stylish::Display for Layout {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                match f.width() {
+                    Some(width) if width < self.0.len() => f.write_str(&self.0[..1]),
+                    _ => f.write_str(self.0),
+                }
+            }
+        }
+
+        struct Float(f64);
+
+        impl stylish::Display for Float {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.handles_precision();
+                let precision = f.precision().unwrap_or(1);
+                f.write_str(&std::format!("{:.*}", precision, self.0))
+            }
+        }
+
+        struct Flags;
+
+        impl stylish::Display for Flags {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.handles_width();
+                f.handles_precision();
+                let flags = std::format!(
+                    "{:?} {:?} {} {} {} {} {:?} {:?}",
+                    f.fill(),
+                    f.align(),
+                    f.alternate(),
+                    f.sign_plus(),
+                    f.sign_minus(),
+                    f.sign_aware_zero_pad(),
+                    f.width(),
+                    f.precision(),
+                );
+                f.write_str(&flags)
+            }
+        }
+
+        assert_eq!(
+            stylish::plain::format!("[{:>8s}]", Name("ab")),
+            "[      ab]"
+        );
+        assert_eq!(stylish::plain::format!("[{:.2s}]", Name("abc")), "[ab]");
+        assert_eq!(
+            stylish::plain::format!("[{:-^5.1s}]", Name("abc")),
+            "[--a--]"
+        );
+        assert_eq!(
+            stylish::html::format!("[{:*<4s}]", Name("ab")),
+            "[<span style=font-weight:bolder>ab**</span>]"
+        );
+        assert_eq!(
+            stylish::html::format!("[{:(fg=red)>3s}]", Name("ab")),
+            "[<span style=color:red;font-weight:bolder> ab</span>]"
+        );
+
+        assert_eq!(stylish::plain::format!("[{:s}]", Num(5)), "[101]");
+        assert_eq!(stylish::plain::format!("[{:+#s}]", Num(5)), "[+0b101]");
+        assert_eq!(stylish::plain::format!("[{:6s}]", Num(-5)), "[  -101]");
+        assert_eq!(stylish::plain::format!("[{:<6s}]", Num(5)), "[101   ]");
+        assert_eq!(stylish::plain::format!("[{:#08s}]", Num(-5)), "[-0b00101]");
+
+        // Reading the width or precision doesn't stop them being applied
+        // automatically, only marking them as handled does
+        assert_eq!(stylish::plain::format!("[{:2s}]", Layout("yes")), "[y ]");
+        assert_eq!(
+            stylish::plain::format!("[{:5.2s}]", Layout("yes")),
+            "[ye   ]"
+        );
+        assert_eq!(
+            stylish::plain::format!("[{:>5s}]", Layout("yes")),
+            "[  yes]"
+        );
+        assert_eq!(
+            stylish::plain::format!("[{:>6.2s}]", Float(1.23456)),
+            "[  1.23]"
+        );
+        assert_eq!(stylish::plain::format!("[{:3s}]", Float(2.0)), "[2.0]");
+
+        assert_eq!(
+            stylish::plain::format!("{:s}", Flags),
+            "' ' None false false false false None None"
+        );
+        assert_eq!(
+            stylish::plain::format!("{:_<+#08.3s}", Flags),
+            "'_' Some(Left) true true false true Some(8) Some(3)"
+        );
+    }
+
+    #[test]
+    fn debug_builders() {
+        use stylish::{Color, Foreground, Intensity};
+
+        struct Point;
+
+        impl stylish::Debug for Point {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.debug_struct("Point")
+                    .name_style(Intensity::Bold)
+                    .punctuation_style(Foreground(Color::Red))
+                    .value_style(Intensity::Faint)
+                    .field("x", &1)
+                    .finish()
+            }
+        }
+
+        struct Pair;
+
+        impl stylish::Debug for Pair {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.debug_tuple("")
+                    .value_style(Intensity::Bold)
+                    .field(&"a")
+                    .finish()
+            }
+        }
+
+        struct Nested;
+
+        impl stylish::Debug for Nested {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.debug_map()
+                    .key_style(Foreground(Color::Green))
+                    .entry(&"p", &Point)
+                    .finish_non_exhaustive()
+            }
+        }
+
+        assert_eq!(
+            stylish::html::format!("{:s?}", Point),
+            "Point<span style=color:red> { </span>\
+            <span style=font-weight:bolder>x</span>\
+            <span style=color:red>: </span>\
+            <span style=color:cyan;font-weight:lighter>1</span>\
+            <span style=color:red> }</span>"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(fg=cyan)s?}", Point),
+            "<span style=color:cyan>Point</span>\
+            <span style=color:red> { </span>\
+            <span style=color:cyan;font-weight:bolder>x</span>\
+            <span style=color:red>: </span>\
+            <span style=color:cyan;font-weight:lighter>1</span>\
+            <span style=color:red> }</span>"
+        );
+        assert_eq!(
+            stylish::html::format!("{:s?}", Pair),
+            "(<span style=color:green;font-weight:bolder>&quot;a&quot;</span>,)"
+        );
+        assert_eq!(
+            stylish::plain::format!("{:s?} {:#s?}", Nested, Nested),
+            "{\"p\": Point { x: 1 }, ..} {\n    \"p\": Point {\n        x: 1,\n    },\n    ..\n}"
+        );
+        assert_eq!(
+            stylish::html::format!("{:#s?}", Point),
+            "Point<span style=color:red> {\n</span>    \
+            <span style=font-weight:bolder>x</span>\
+            <span style=color:red>: </span>\
+            <span style=color:cyan;font-weight:lighter>1</span>\
+            <span style=color:red>,\n}</span>"
+        );
+    }
+
+    #[test]
+    fn debug() {
+        use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+        #[derive(Debug)]
+        struct OnlyStd(#[allow(dead_code)] u8);
+
+        let map = BTreeMap::from([(1, "a"), (2, "b")]);
+        let set = BTreeSet::from(['x', 'y']);
+        let ok: Result<u8, ()> = Ok(1);
+        let err: Result<u8, ()> = Err(());
+
+        assert_eq!(stylish::plain::format!("{:s?}", 1.5), "1.5");
+        assert_eq!(stylish::plain::format!("{:s?}", "a\"b"), r#""a\"b""#);
+        assert_eq!(
+            stylish::plain::format!("{:s?} {:s?} {:s?}", Some(true), None::<u8>, ()),
+            "Some(true) None ()"
+        );
+        assert_eq!(
+            stylish::plain::format!("{:s?} {:s?}", ok, err),
+            "Ok(1) Err(())"
+        );
+        assert_eq!(
+            stylish::plain::format!("{:s?} {:s?} {:s?}", (1,), (1, 'c', "s"), [0u8; 2]),
+            r#"(1,) (1, 'c', "s") [0, 0]"#
+        );
+        assert_eq!(
+            stylish::plain::format!("{:s?} {:s?} {:s?}", vec![1], &[2][..], map),
+            r#"[1] [2] {1: "a", 2: "b"}"#
+        );
+        assert_eq!(
+            stylish::plain::format!(
+                "{:s?} {:s?} {:s?}",
+                set,
+                HashMap::from([(1, 2)]),
+                HashSet::from([3])
+            ),
+            "{'x', 'y'} {1: 2} {3}"
+        );
+        assert_eq!(
+            stylish::plain::format!("{:s?} {:#s?}", Some(OnlyStd(1)), OnlyStd(1)),
+            "Some(OnlyStd(1)) OnlyStd(\n    1,\n)"
+        );
+        assert_eq!(
+            stylish::plain::format!("{:#s?}", Some((1, "a"))),
+            "Some(\n    (\n        1,\n        \"a\",\n    ),\n)"
+        );
+        assert_eq!(
+            stylish::plain::format!("[{:>4s?}] [{:.1s?}] [{:03s?}]", 1, 0.25, [1, 2]),
+            "[   1] [0.2] [[001, 002]]"
+        );
+
+        assert_eq!(
+            stylish::html::format!("{:s?}", ok),
+            "<span style=color:magenta>Ok</span>(<span style=color:cyan>1</span>)"
+        );
+        assert_eq!(
+            stylish::html::format!("{:s?}", (true, 'c')),
+            "(<span style=color:magenta>true</span>, <span style=color:green>&#x27;c&#x27;</span>)"
+        );
+        assert_eq!(
+            stylish::html::format!("{:(bold)s?}", [OnlyStd(1)]),
+            "<span style=font-weight:bolder>[OnlyStd(1)]</span>"
+        );
+
+        let theme = [
+            ("debug.keyword", "fg=blue".parse().unwrap()),
+            ("debug.punctuation", "fg=red".parse().unwrap()),
+        ];
+        let mut writer = stylish::Themed::new(stylish::html(String::new()), theme);
+        stylish::write!(writer, "{:s?}", [None::<u8>]).unwrap();
+        assert_eq!(
+            writer.into_inner().finish().unwrap(),
+            "<span style=color:red>[</span>\
+            <span style=color:blue>None</span>\
+            <span style=color:red>]</span>"
+        );
+    }
+
+    #[test]
+    fn parse_style() {
+        use stylish::{Color, Intensity, ParseStyleErrorKind, Style, StyleDiff, Underline};
+
+        let diff: StyleDiff =
+            " fg = bright-red , bg=rgb(1, 2, 3),bold,underline=curly,ul=21,italic,"
+                .parse()
+                .unwrap();
+        let mut expected = StyleDiff::default();
+        expected.foreground = Some(Color::BrightRed);
+        expected.background = Some(Color::Rgb(1, 2, 3));
+        expected.intensity = Some(Intensity::Bold);
+        expected.underline = Some(Underline::Curly);
+        expected.underline_color = Some(Color::Ansi256(21));
+        expected.italic = Some(true);
+        assert_eq!(diff, expected);
+        assert_eq!(
+            diff.to_string(),
+            "fg=bright-red,bg=#010203,bold,italic,underline=curly,ul=21"
+        );
+        assert_eq!(diff.to_string().parse(), Ok(diff));
+
+        assert_eq!("".parse(), Ok(StyleDiff::default()));
+        assert_eq!("".parse(), Ok(Style::default()));
+        assert_eq!(Style::default().to_string(), "");
+
+        let error = |s: &str| {
+            let error = s.parse::<StyleDiff>().unwrap_err();
+            (error.kind(), error.span())
+        };
+        assert_eq!(error("fg"), (ParseStyleErrorKind::MissingValue, 0..2));
+        assert_eq!(error("fg="), (ParseStyleErrorKind::InvalidColor, 3..3));
+        assert_eq!(
+            error("bold, fg=#f80"),
+            (ParseStyleErrorKind::InvalidColor, 9..13)
+        );
+        assert_eq!(
+            error("fg=rgb(1,2)"),
+            (ParseStyleErrorKind::InvalidColor, 3..11)
+        );
+        assert_eq!(error("fg=256"), (ParseStyleErrorKind::InvalidColor, 3..6));
+        assert_eq!(
+            error("bold=true"),
+            (ParseStyleErrorKind::UnexpectedValue, 5..9)
+        );
+        assert_eq!(
+            error("italic=yes"),
+            (ParseStyleErrorKind::InvalidValue, 7..10)
+        );
+        assert_eq!(
+            error("underline=wavy"),
+            (ParseStyleErrorKind::InvalidValue, 10..14)
+        );
+        assert_eq!(
+            error("bold,,faint"),
+            (ParseStyleErrorKind::EmptyAttribute, 5..5)
+        );
+        assert_eq!(error(","), (ParseStyleErrorKind::EmptyAttribute, 0..0));
+        assert_eq!(
+            error("bolder"),
+            (ParseStyleErrorKind::UnknownAttribute, 0..6)
+        );
+    }
+
+    #[test]
+    fn parse_style_in_format_string() {
+        assert_eq!(
+            stylish::ansi::format!("{:(fg=bright-red, bg=rgb(1, 2, 3))}", 2),
+            "\x1b[91;48;2;1;2;3m2\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn theme() {
+        use std::collections::HashMap;
+
+        use stylish::{ParseStyleErrorKind, StyleDiff, Themed};
+
+        struct Path(&'static str);
+
+        impl stylish::Display for Path {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.with_class("path").write_str(self.0)
+            }
+        }
+
+        let mut theme = HashMap::new();
+        theme.insert("error", "fg=red,bold".parse::<StyleDiff>().unwrap());
+        theme.insert("path", "underline".parse().unwrap());
+
+        let mut writer = Themed::new(stylish::ansi(String::new()), &theme);
+        stylish::write!(
+            writer,
+            "{:(.error)} {:(class=error,normal)} {:(.error,.path, fg=blue)} {:(.missing)} {:(.error)s}",
+            1,
+            2,
+            3,
+            4,
+            Path("x"),
+        )
+        .unwrap();
+        assert_eq!(
+            writer.into_inner().finish().unwrap(),
+            "\x1b[31;1m1\x1b[0m \x1b[31m2\x1b[0m \x1b[34;1;4m3\x1b[0m 4 \x1b[31;1;4mx\x1b[0m"
+        );
+
+        let mut writer = stylish::io::Themed::new(stylish::io::plain(Vec::new()), &theme);
+        stylish::write!(writer, "{:(.error)s}", Path("x")).unwrap();
+        assert_eq!(writer.into_inner().into_inner(), b"x");
+
+        assert_eq!(
+            stylish::ansi::format!("{:(.error)} {:s}", 1, Path("x")),
+            "1 x"
+        );
+
+        let error = ".error".parse::<StyleDiff>().unwrap_err();
+        assert_eq!(error.kind(), ParseStyleErrorKind::UnknownAttribute);
+        assert_eq!(error.span(), 1..6);
+    }
+
+    #[test]
+    fn theme_files() {
+        use stylish::{theme::Error, Color, Themed};
+
+        let theme = stylish::theme::from_toml(
+            r#"
+            error = "fg=red"
+
+            [diff]
+            added = "fg=bright-green"
+            "#,
+        )
+        .unwrap();
+        let mut writer = Themed::new(stylish::ansi(String::new()), &theme);
+        stylish::write!(writer, "{:(.error)} {:(.diff.added)}", 1, 2).unwrap();
+        assert_eq!(
+            writer.into_inner().finish().unwrap(),
+            "\x1b[31m1\x1b[0m \x1b[92m2\x1b[0m"
+        );
+
+        let error = stylish::theme::from_toml("[diff]\nadded = 'fg=green,'\nremoved = 'bold,,'")
+            .unwrap_err();
+        assert!(
+            matches!(&error, Error::InvalidStyle { key, value, .. } if key == "diff.removed" && value == "bold,,")
+        );
+        assert!(matches!(
+            stylish::theme::from_toml("error = 1"),
+            Err(Error::ExpectedString {
+                found: "integer",
+                ..
+            })
+        ));
+        assert!(matches!(
+            stylish::theme::from_toml("error = "),
+            Err(Error::Syntax(_))
+        ));
+
+        assert_eq!(
+            stylish::theme::from_json(r#"{ "diff": { "added": "fg=green" } }"#).unwrap(),
+            theme_from([("diff.added", "fg=green")])
+        );
+        assert!(matches!(
+            stylish::theme::from_json(r#"["fg=green"]"#),
+            Err(Error::Syntax(_))
+        ));
+
+        let mut base24 = String::from("scheme: Test\n");
+        for i in 0..24 {
+            base24 += &format!("base{i:02X}: \"{i:02x}{i:02x}{i:02x}\"\n");
+        }
+        let palette = stylish::theme::base16_palette(&base24).unwrap();
+        assert_eq!(palette.rgb(Color::White), Some((0x06, 0x06, 0x06)));
+        assert_eq!(palette.rgb(Color::BrightBlack), Some((0x02, 0x02, 0x02)));
+        assert_eq!(palette.rgb(Color::BrightRed), Some((0x12, 0x12, 0x12)));
+        assert_eq!(palette.rgb(Color::BrightBlue), Some((0x16, 0x16, 0x16)));
+
+        let palette =
+            stylish::theme::base16_palette(&base24.replace("base10", "unused").replace('"', ""))
+                .unwrap();
+        assert_eq!(palette.rgb(Color::Black), Some((0, 0, 0)));
+        assert_eq!(palette.rgb(Color::White), Some((0x05, 0x05, 0x05)));
+        assert_eq!(palette.rgb(Color::BrightRed), Some((0x08, 0x08, 0x08)));
+
+        let error = stylish::theme::base16_palette("palette:\n  base00: '000000'").unwrap_err();
+        assert_eq!(error.to_string(), "missing color `palette.base08`");
+
+        let mut writer = stylish::Html::new(String::new()).palette(palette);
+        stylish::write!(writer, "{:(fg=red,bg=15,ul=bright-black)}", 1).unwrap();
+        assert_eq!(
+            writer.finish().unwrap(),
+            "<span style=color:#080808;background-color:#070707;text-decoration-color:#030303>1</span>"
+        );
+    }
+
+    fn theme_from<const N: usize>(
+        entries: [(&str, &str); N],
+    ) -> std::collections::BTreeMap<String, stylish::StyleDiff> {
+        entries
+            .into_iter()
+            .map(|(class, style)| (class.to_owned(), style.parse().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn serde() {
+        use stylish::{Color, Foreground, Intensity, Style, StyleDiff};
+
+        let style = Style::default()
+            .with(Foreground(Color::Ansi256(208)))
+            .with(Intensity::Faint);
+        let json = serde_json::to_string(&style).unwrap();
+        assert_eq!(json, r#"{"foreground":"208","intensity":"faint"}"#);
+        assert_eq!(serde_json::from_str::<Style>(&json).unwrap(), style);
+        assert_eq!(
+            serde_json::from_str::<Style>("{}").unwrap(),
+            Style::default()
+        );
+
+        let error = serde_json::from_str::<StyleDiff>(r#"{"foreground":"reddish"}"#).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(r#"invalid value: string "reddish", expected a color"#));
+        assert!(serde_json::from_str::<StyleDiff>(r#"{"colour":"red"}"#).is_err());
+
+        struct Docs;
+
+        impl stylish::Display for Docs {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.link("https://example.com", |f| {
+                    f.write_str("see ")?;
+                    f.with(Intensity::Bold).write_str("docs")
+                })
+            }
+        }
+
+        let string = stylish::format!("{:(fg=red)}: {:s}", "error", Docs);
+        let json = serde_json::to_string(&string).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"text":"error","style":{"foreground":"red"}},{"text":": "},{"text":"see ","link":"https://example.com"},{"text":"docs","style":{"intensity":"bold"},"link":"https://example.com"}]"#
+        );
+        let string: stylish::String = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            stylish::html::format!("{:s}", string),
+            r#"<span style=color:red>error</span>: <a href="https://example.com">see <span style=font-weight:bolder>docs</span></a>"#
+        );
+    }
+
+    #[test]
+    fn link() {
+        struct Docs(&'static str);
+
+        impl stylish::Display for Docs {
+            fn fmt(&self, f: &mut stylish::Formatter<'_>) -> stylish::Result {
+                f.link(self.0, |f| {
+                    f.write_str("docs ")?;
+                    f.link("https://ignored.example", |f| {
+                        f.with(stylish::Foreground(stylish::Color::Red))
+                            .write_str("here")
+                    })
+                })
+            }
+        }
+
+        let docs = Docs("https://example.com/a b");
+        assert_eq!(
+            stylish::ansi::format!("{:s}", docs),
+            "\x1b]8;;https://example.com/a%20b\x1b\\docs \x1b[31mhere\x1b]8;;\x1b\\\x1b[0m"
+        );
+        assert_eq!(
+            stylish::html::format!("{:s}", docs),
+            "<a href=\"https://example.com/a%20b\">docs <span style=color:red>here</span></a>"
+        );
+        assert_eq!(stylish::plain::format!("{:s}", docs), "docs here");
+        assert_eq!(
+            stylish::html::format!("[{:>10s}]", docs),
+            "[ <a href=\"https://example.com/a%20b\">docs <span style=color:red>here</span></a>]"
+        );
+
+        let mut writer = stylish::Plain::new(String::new()).render_links(true);
+        stylish::write!(writer, "{:s}", docs).unwrap();
+        assert_eq!(writer.into_inner(), "docs here (https://example.com/a b)");
+
+        let string = stylish::format!("see {:s}!", docs);
+        assert_eq!(
+            stylish::html::format!("{:s}", string),
+            "see <a href=\"https://example.com/a%20b\">docs <span style=color:red>here</span></a>!"
+        );
+    }
+
     #[test]
     fn builtin_macros() {
         assert_eq!(stylish::plain::format!(concat!("a", "b")), "ab");
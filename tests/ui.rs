@@ -0,0 +1,19 @@
+#[cfg(not(feature = "_tests"))]
+compile_error!("please test with --all-features");
+
+#[cfg(feature = "_tests")]
+#[test]
+fn ui() {
+    // The snapshots are of the stable compiler's output, where errors in a
+    // format string point to the whole literal, nightly compilers can point
+    // within it so produce different output
+    let version = std::process::Command::new("rustc")
+        .arg("--version")
+        .output()
+        .unwrap();
+    if String::from_utf8_lossy(&version.stdout).contains("nightly") {
+        return;
+    }
+
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
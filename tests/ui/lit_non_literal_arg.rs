@@ -0,0 +1,4 @@
+fn main() {
+    let name = "Ferris";
+    let _ = stylish::lit!("{:(fg=red)}", name);
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = stylish::format!("{x}", x = 1, 2);
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = stylish::format!("{} {}", 1);
+}
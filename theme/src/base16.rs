@@ -0,0 +1,112 @@
+use stylish_core::Palette;
+use yaml_rust2::{Yaml, YamlLoader};
+
+use crate::Error;
+
+/// The scheme colors used for each palette entry, following the [base16] and
+/// [base24] styling guidelines.
+///
+/// [base16]: https://github.com/tinted-theming/home/blob/main/styling.md
+/// [base24]: https://github.com/tinted-theming/base24/blob/master/styling.md
+const BASE16: [&str; 16] = [
+    "base00", "base08", "base0B", "base0A", "base0D", "base0E", "base0C", "base05", "base03",
+    "base08", "base0B", "base0A", "base0D", "base0E", "base0C", "base07",
+];
+const BASE24: [&str; 16] = [
+    "base00", "base08", "base0B", "base0A", "base0D", "base0E", "base0C", "base06", "base02",
+    "base12", "base14", "base13", "base16", "base17", "base15", "base07",
+];
+
+/// Parse a `rrggbb` color, with or without a leading `#`.
+fn hex(value: &str) -> Option<(u8, u8, u8)> {
+    let value = value.strip_prefix('#').unwrap_or(value);
+    if value.len() != 6 || !value.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i| u8::from_str_radix(&value[i..i + 2], 16).ok();
+    Some((channel(0)?, channel(2)?, channel(4)?))
+}
+
+/// Import a [base16] or [base24] YAML color scheme as a [`Palette`].
+///
+/// Both the original scheme format (with the colors at the top level) and the
+/// newer format (with the colors nested under `palette`) are supported; a
+/// scheme is treated as base24 if it declares `system: base24` or defines
+/// `base10`.
+///
+/// ```rust
+/// use stylish::Color;
+///
+/// let palette = stylish::theme::base16_palette(
+///     r##"
+///     system: "base16"
+///     name: "Default Dark"
+///     palette:
+///       base00: "#181818"
+///       base01: "#282828"
+///       base02: "#383838"
+///       base03: "#585858"
+///       base04: "#b8b8b8"
+///       base05: "#d8d8d8"
+///       base06: "#e8e8e8"
+///       base07: "#f8f8f8"
+///       base08: "#ab4642"
+///       base09: "#dc9656"
+///       base0A: "#f7ca88"
+///       base0B: "#a1b56c"
+///       base0C: "#86c1b9"
+///       base0D: "#7cafc2"
+///       base0E: "#ba8baf"
+///       base0F: "#a16946"
+///     "##,
+/// )?;
+/// assert_eq!(palette.rgb(Color::Red), Some((0xab, 0x46, 0x42)));
+/// assert_eq!(palette.rgb(Color::BrightRed), Some((0xab, 0x46, 0x42)));
+/// assert_eq!(palette.rgb(Color::BrightWhite), Some((0xf8, 0xf8, 0xf8)));
+///
+/// let error = stylish::theme::base16_palette("base00: '#18181'").unwrap_err();
+/// assert_eq!(error.to_string(), "invalid color `#18181` for `base00`");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [base16]: https://github.com/tinted-theming/home
+/// [base24]: https://github.com/tinted-theming/base24
+pub fn base16_palette(input: &str) -> Result<Palette, Error> {
+    let documents =
+        YamlLoader::load_from_str(input).map_err(|error| Error::Syntax(error.into()))?;
+    let scheme = documents.first().unwrap_or(&Yaml::BadValue);
+
+    let (colors, prefix) = match &scheme["palette"] {
+        Yaml::BadValue => (scheme, ""),
+        palette => (palette, "palette."),
+    };
+    let base24 = scheme["system"].as_str() == Some("base24") || !colors["base10"].is_badvalue();
+
+    let mut palette = Palette::XTERM;
+    for (rgb, name) in palette
+        .colors
+        .iter_mut()
+        .zip(if base24 { BASE24 } else { BASE16 })
+    {
+        let key = || format!("{prefix}{name}");
+        let value = match &colors[name] {
+            Yaml::String(value) => value.clone(),
+            // Unquoted colors consisting only of digits are parsed as integers
+            Yaml::Integer(value) if *value >= 0 => format!("{value:06}"),
+            Yaml::BadValue => return Err(Error::MissingColor { key: key() }),
+            value => {
+                let found = match value {
+                    Yaml::Real(_) | Yaml::Integer(_) => "number",
+                    Yaml::Boolean(_) => "boolean",
+                    Yaml::Array(_) => "array",
+                    Yaml::Hash(_) => "mapping",
+                    _ => "null",
+                };
+                return Err(Error::ExpectedString { key: key(), found });
+            }
+        };
+        *rgb = hex(&value).ok_or_else(|| Error::InvalidColor { key: key(), value })?;
+    }
+
+    Ok(palette)
+}
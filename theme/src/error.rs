@@ -0,0 +1,67 @@
+use std::{error::Error as StdError, fmt};
+
+use stylish_core::ParseStyleError;
+
+/// An error encountered while loading a theme or color scheme.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The input could not be parsed as the expected file format.
+    Syntax(Box<dyn StdError + Send + Sync>),
+    /// The value for `key` was not a string.
+    ExpectedString {
+        /// The (dotted) key of the value.
+        key: String,
+        /// The kind of value that was found instead.
+        found: &'static str,
+    },
+    /// The value for `key` was not a valid style.
+    InvalidStyle {
+        /// The (dotted) key of the style.
+        key: String,
+        /// The invalid style string.
+        value: String,
+        /// Why the style string was rejected, the span is relative to `value`.
+        error: ParseStyleError,
+    },
+    /// The value for `key` was not a valid hex color.
+    InvalidColor {
+        /// The (dotted) key of the color.
+        key: String,
+        /// The invalid color string.
+        value: String,
+    },
+    /// A color required by the color scheme was not specified.
+    MissingColor {
+        /// The key the color was expected at.
+        key: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(error) => error.fmt(f),
+            Self::ExpectedString { key, found } => {
+                write!(f, "expected a string for `{key}`, found {found}")
+            }
+            Self::InvalidStyle { key, value, error } => {
+                write!(f, "invalid style `{value}` for `{key}`: {error}")
+            }
+            Self::InvalidColor { key, value } => {
+                write!(f, "invalid color `{value}` for `{key}`")
+            }
+            Self::MissingColor { key } => write!(f, "missing color `{key}`"),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Syntax(error) => Some(&**error),
+            Self::InvalidStyle { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! [`stylish`] helpers for loading themes and color schemes from files.
+//!
+//! Themes map class names to style strings, using the same attribute syntax as
+//! format strings; nested tables are flattened into dotted class names. They
+//! can be loaded from TOML (with the `toml` feature) or JSON (with the `json`
+//! feature):
+//!
+//! ```toml
+//! error = "fg=red, bold"
+//! path = "fg=cyan, underline"
+//!
+//! [diff]
+//! added = "fg=green"
+//! removed = "fg=red"
+//! ```
+//!
+//! With the `base16` feature [base16 and base24] YAML color schemes can be
+//! imported as a [`Palette`](stylish_core::Palette) for renderers that display
+//! exact RGB colors.
+//!
+//! [base16 and base24]: https://github.com/tinted-theming/home
+
+#![doc(test(attr(deny(warnings))))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+#[cfg(doc)]
+extern crate self as stylish;
+
+#[cfg(feature = "base16")]
+mod base16;
+mod error;
+#[cfg(any(feature = "toml", feature = "json"))]
+mod map;
+
+#[cfg(feature = "base16")]
+pub use self::base16::base16_palette;
+pub use self::error::Error;
+#[cfg(feature = "json")]
+pub use self::map::from_json;
+#[cfg(feature = "toml")]
+pub use self::map::from_toml;
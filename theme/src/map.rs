@@ -0,0 +1,134 @@
+use std::collections::BTreeMap;
+
+use stylish_core::StyleDiff;
+
+use crate::Error;
+
+/// Parse `value` as the style for `key` and add it to `theme`.
+fn insert(theme: &mut BTreeMap<String, StyleDiff>, key: String, value: &str) -> Result<(), Error> {
+    match value.parse() {
+        Ok(diff) => {
+            theme.insert(key, diff);
+            Ok(())
+        }
+        Err(error) => Err(Error::InvalidStyle {
+            key,
+            value: value.to_owned(),
+            error,
+        }),
+    }
+}
+
+/// Join a table's key onto the key of the table it is nested in.
+fn join(prefix: Option<&str>, key: &str) -> String {
+    match prefix {
+        Some(prefix) => format!("{prefix}.{key}"),
+        None => key.to_owned(),
+    }
+}
+
+/// Load a theme from a TOML document mapping class names to style strings.
+///
+/// ```rust
+/// let theme = stylish::theme::from_toml(
+///     r#"
+///     error = "fg=red, bold"
+///
+///     [diff]
+///     added = "fg=green"
+///     "#,
+/// )?;
+///
+/// let mut writer = stylish::Themed::new(stylish::html(String::new()), theme);
+/// stylish::write!(writer, "{:(.error)} {:(.diff.added)}", "error", "+")?;
+/// assert_eq!(
+///     writer.into_inner().finish()?,
+///     "<span style=color:red;font-weight:bolder>error</span> \
+///      <span style=color:green>+</span>",
+/// );
+///
+/// let error = stylish::theme::from_toml("error = 'fg=reddish'").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "invalid style `fg=reddish` for `error`: invalid color at 3..10",
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "toml")]
+pub fn from_toml(input: &str) -> Result<BTreeMap<String, StyleDiff>, Error> {
+    fn collect(
+        theme: &mut BTreeMap<String, StyleDiff>,
+        prefix: Option<&str>,
+        table: &toml::Table,
+    ) -> Result<(), Error> {
+        for (key, value) in table {
+            let key = join(prefix, key);
+            match value {
+                toml::Value::String(value) => insert(theme, key, value)?,
+                toml::Value::Table(table) => collect(theme, Some(&key), table)?,
+                value => {
+                    return Err(Error::ExpectedString {
+                        key,
+                        found: value.type_str(),
+                    })
+                }
+            }
+        }
+        Ok(())
+    }
+
+    let table = input
+        .parse::<toml::Table>()
+        .map_err(|error| Error::Syntax(error.into()))?;
+    let mut theme = BTreeMap::new();
+    collect(&mut theme, None, &table)?;
+    Ok(theme)
+}
+
+/// Load a theme from a JSON object mapping class names to style strings.
+///
+/// ```rust
+/// let theme = stylish::theme::from_json(
+///     r#"{ "error": "fg=red, bold", "diff": { "added": "fg=green" } }"#,
+/// )?;
+/// assert_eq!(theme["diff.added"], "fg=green".parse()?);
+///
+/// let error = stylish::theme::from_json(r#"{ "error": 1 }"#).unwrap_err();
+/// assert_eq!(error.to_string(), "expected a string for `error`, found number");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "json")]
+pub fn from_json(input: &str) -> Result<BTreeMap<String, StyleDiff>, Error> {
+    use serde_json::{Map, Value};
+
+    fn collect(
+        theme: &mut BTreeMap<String, StyleDiff>,
+        prefix: Option<&str>,
+        object: &Map<String, Value>,
+    ) -> Result<(), Error> {
+        for (key, value) in object {
+            let key = join(prefix, key);
+            let found = match value {
+                Value::String(value) => {
+                    insert(theme, key, value)?;
+                    continue;
+                }
+                Value::Object(object) => {
+                    collect(theme, Some(&key), object)?;
+                    continue;
+                }
+                Value::Null => "null",
+                Value::Bool(_) => "boolean",
+                Value::Number(_) => "number",
+                Value::Array(_) => "array",
+            };
+            return Err(Error::ExpectedString { key, found });
+        }
+        Ok(())
+    }
+
+    let object = serde_json::from_str(input).map_err(|error| Error::Syntax(error.into()))?;
+    let mut theme = BTreeMap::new();
+    collect(&mut theme, None, &object)?;
+    Ok(theme)
+}